@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+use std::ops::Range;
+
+use crate::parse;
+use crate::solution::{Lines, Solution, Streaming};
+
+// The sums of two consecutive windows of `k` depths share all but the first depth
+// of the first window and the last depth of the second one, so comparing the sums
+// is comparing depths[i] and depths[i + k].
+
+/// Number of windows of `window` depths whose sum is larger than the previous window's.
+pub fn count_window_increases(depths: &[i32], window: usize) -> usize {
+    depths
+        .iter()
+        .zip(depths.iter().skip(window))
+        .filter(|(x, y)| x < y)
+        .count()
+}
+
+#[derive(Debug)]
+pub struct WindowReport {
+    pub window: usize,
+    /// Index of the first depth of each window whose sum is larger than the previous window's.
+    pub increases: Vec<usize>,
+    /// Windows of the longest run of increasing sums, empty if there are no windows.
+    pub longest_run: Range<usize>,
+}
+
+pub fn window_report(depths: &[i32], window: usize) -> WindowReport {
+    let increases: Vec<usize> = depths
+        .iter()
+        .zip(depths.iter().skip(window))
+        .enumerate()
+        .filter(|(_, (x, y))| x < y)
+        .map(|(i, _)| i + 1)
+        .collect();
+
+    let window_count = (depths.len() + 1).saturating_sub(window);
+    let mut longest_run = 0..window_count.min(1);
+    let mut run_start = 0;
+    for (i, &increase) in increases.iter().enumerate() {
+        if i == 0 || increases[i - 1] + 1 != increase {
+            run_start = increase - 1;
+        }
+        if increase + 1 - run_start > longest_run.len() {
+            longest_run = run_start..increase + 1;
+        }
+    }
+    WindowReport {
+        window,
+        increases,
+        longest_run,
+    }
+}
+
+impl fmt::Display for WindowReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "windows of {} depths", self.window)?;
+        writeln!(f, "increases: {}", self.increases.len())?;
+        if self.longest_run.is_empty() {
+            writeln!(f, "longest increasing run: none")?;
+        } else {
+            writeln!(
+                f,
+                "longest increasing run: {} windows, from window {} to {}",
+                self.longest_run.len(),
+                self.longest_run.start,
+                self.longest_run.end - 1
+            )?;
+        }
+        write!(
+            f,
+            "increasing windows: {}",
+            self.increases
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Depths fed one at a time, only the last `window` ones are kept.
+struct WindowCounter {
+    window: usize,
+    recent: VecDeque<i32>,
+    increases: usize,
+}
+
+impl WindowCounter {
+    fn new(window: usize) -> WindowCounter {
+        WindowCounter {
+            window,
+            recent: VecDeque::with_capacity(window),
+            increases: 0,
+        }
+    }
+
+    fn push(&mut self, depth: i32) {
+        if self.recent.len() == self.window {
+            let first = self.recent.pop_front();
+            if first.is_some_and(|first| first < depth) {
+                self.increases += 1;
+            }
+        }
+        self.recent.push_back(depth);
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Input = Lines<i32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(count_window_increases(&input.0, 1))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(count_window_increases(&input.0, 3))
+    }
+}
+
+impl Streaming for Day01 {
+    fn solve_stream(reader: &mut dyn BufRead) -> Result<(usize, usize), Box<dyn Error>> {
+        let mut part1 = WindowCounter::new(1);
+        let mut part2 = WindowCounter::new(3);
+        for depth in parse::read_lines::<i32>(reader) {
+            let depth = depth?;
+            part1.push(depth);
+            part2.push(depth);
+        }
+        Ok((part1.increases, part2.increases))
+    }
+}
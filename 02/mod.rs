@@ -0,0 +1,186 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError, ParseErrorKind};
+use crate::solution::{Lines, Solution, Streaming};
+
+#[derive(Debug)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+    Back(i32),
+    /// Turns around, forward and back swap directions.
+    Turn,
+    Wait,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Command::*;
+        match s {
+            "turn" => return Ok(Turn),
+            "wait" => return Ok(Wait),
+            _ => (),
+        }
+        let (c, n) = parse::split_once(s, s, " ")?;
+        let n = parse::number(s, n)?;
+        match c {
+            "forward" => Ok(Forward(n)),
+            "down" => Ok(Down(n)),
+            "up" => Ok(Up(n)),
+            "back" => Ok(Back(n)),
+            _ => Err(ParseError::at(
+                s,
+                c,
+                ParseErrorKind::Invalid(format!("bad command name {:?}", c)),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Command::*;
+        match self {
+            Forward(n) => write!(f, "forward {}", n),
+            Down(n) => write!(f, "down {}", n),
+            Up(n) => write!(f, "up {}", n),
+            Back(n) => write!(f, "back {}", n),
+            Turn => write!(f, "turn"),
+            Wait => write!(f, "wait"),
+        }
+    }
+}
+
+/// How down and up move the submarine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    /// Down and up change the depth.
+    Plain,
+    /// Down and up change the aim, the depth changes by the aim times the distance travelled.
+    Aim,
+}
+
+#[derive(Debug, Clone)]
+pub struct Submarine {
+    pub model: Model,
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+    /// 1 when forward increases the horizontal position, -1 once turned around.
+    pub heading: i64,
+}
+
+impl Submarine {
+    pub fn new(model: Model) -> Submarine {
+        Submarine {
+            model,
+            horizontal: 0,
+            depth: 0,
+            aim: 0,
+            heading: 1,
+        }
+    }
+
+    /// Fails when the position or the aim no longer fit in 64 bits.
+    pub fn execute(&mut self, command: &Command) -> Result<(), Box<dyn Error>> {
+        use Command::*;
+        match (command, self.model) {
+            (Forward(n), _) => self.advance(*n as i64)?,
+            (Back(n), _) => self.advance(-(*n as i64))?,
+            (Down(n), Model::Plain) => self.depth = moved(self.depth, *n as i64)?,
+            (Up(n), Model::Plain) => self.depth = moved(self.depth, -(*n as i64))?,
+            (Down(n), Model::Aim) => self.aim = moved(self.aim, *n as i64)?,
+            (Up(n), Model::Aim) => self.aim = moved(self.aim, -(*n as i64))?,
+            (Turn, _) => self.heading = -self.heading,
+            (Wait, _) => (),
+        }
+        Ok(())
+    }
+
+    fn advance(&mut self, distance: i64) -> Result<(), Box<dyn Error>> {
+        self.horizontal = moved(self.horizontal, self.heading * distance)?;
+        if self.model == Model::Aim {
+            let sink = self.aim.checked_mul(distance).ok_or(OVERFLOW)?;
+            self.depth = moved(self.depth, sink)?;
+        }
+        Ok(())
+    }
+
+    pub fn position_product(&self) -> i128 {
+        self.horizontal as i128 * self.depth as i128
+    }
+
+    /// Executes `commands` writing the state after each of them as CSV,
+    /// the first row being the initial state.
+    pub fn trace<'a>(
+        &mut self,
+        commands: impl IntoIterator<Item = &'a Command>,
+        out: &mut dyn Write,
+    ) -> Result<(), Box<dyn Error>> {
+        writeln!(out, "step,command,horizontal,depth,aim,heading")?;
+        self.write_row(out, 0, "")?;
+        for (i, command) in commands.into_iter().enumerate() {
+            self.execute(command)?;
+            self.write_row(out, i + 1, &command.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn write_row(&self, out: &mut dyn Write, step: usize, command: &str) -> io::Result<()> {
+        writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            step, command, self.horizontal, self.depth, self.aim, self.heading
+        )
+    }
+}
+
+const OVERFLOW: &str = "submarine went beyond 64 bits";
+
+fn moved(from: i64, by: i64) -> Result<i64, Box<dyn Error>> {
+    Ok(from.checked_add(by).ok_or(OVERFLOW)?)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Lines<Command>;
+    type Part1 = i128;
+    type Part2 = i128;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        let mut submarine = Submarine::new(Model::Plain);
+        for c in &input.0 {
+            submarine.execute(c)?;
+        }
+        Ok(submarine.position_product())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        let mut submarine = Submarine::new(Model::Aim);
+        for c in &input.0 {
+            submarine.execute(c)?;
+        }
+        Ok(submarine.position_product())
+    }
+}
+
+impl Streaming for Day02 {
+    fn solve_stream(reader: &mut dyn BufRead) -> Result<(i128, i128), Box<dyn Error>> {
+        let mut plain = Submarine::new(Model::Plain);
+        let mut aim = Submarine::new(Model::Aim);
+        for c in parse::read_lines::<Command>(reader) {
+            let c = c?;
+            plain.execute(&c)?;
+            aim.execute(&c)?;
+        }
+        Ok((plain.position_product(), aim.position_product()))
+    }
+}
@@ -0,0 +1,222 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::number::BigUint;
+use crate::parse::{ParseError, ParseErrorKind};
+use crate::solution::Solution;
+
+/// Set of rows of the report, one bit per row packed in `u64`s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RowSet(Vec<u64>);
+
+impl RowSet {
+    fn empty(rows: usize) -> RowSet {
+        RowSet(vec![0; rows.div_ceil(64)])
+    }
+
+    fn full(rows: usize) -> RowSet {
+        let mut set = RowSet(vec![u64::MAX; rows.div_ceil(64)]);
+        if !rows.is_multiple_of(64) {
+            if let Some(last) = set.0.last_mut() {
+                *last = (1 << (rows % 64)) - 1;
+            }
+        }
+        set
+    }
+
+    fn insert(&mut self, row: usize) {
+        self.0[row / 64] |= 1 << (row % 64);
+    }
+
+    fn contains(&self, row: usize) -> bool {
+        self.0[row / 64] & 1 << (row % 64) != 0
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Number of rows in both sets.
+    fn intersection_len(&self, other: &RowSet) -> usize {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Keeps the rows in `other` if `keep_in_other`, the rows outside of it otherwise.
+    fn retain(&mut self, other: &RowSet, keep_in_other: bool) {
+        for (a, b) in self.0.iter_mut().zip(&other.0) {
+            *a &= if keep_in_other { *b } else { !b };
+        }
+    }
+
+    fn first(&self) -> Option<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .find(|(_, w)| **w != 0)
+            .map(|(i, w)| i * 64 + w.trailing_zeros() as usize)
+    }
+}
+
+/// Diagnostic numbers stored column by column, `columns[i]` being the set of rows
+/// whose `i`th bit from the left is 1, so bits are counted a word of rows at a time.
+pub struct Report {
+    rows: usize,
+    columns: Vec<RowSet>,
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s.lines().count();
+        let width = s.lines().next().map_or(0, |l| l.len());
+        if rows == 0 || width == 0 {
+            return Err(ParseError::at(s, s, ParseErrorKind::Empty));
+        }
+        let mut columns = vec![RowSet::empty(rows); width];
+        for (row, l) in s.lines().enumerate() {
+            if l.len() != width {
+                return Err(ParseError::at(
+                    s,
+                    l,
+                    ParseErrorKind::Invalid(format!(
+                        "number of {} bits, expected {}",
+                        l.len(),
+                        width
+                    )),
+                ));
+            }
+            for (i, c) in l.char_indices() {
+                match c {
+                    '1' => columns[i].insert(row),
+                    '0' => (),
+                    _ => return Err(ParseError::at(s, &l[i..], ParseErrorKind::InvalidChar(c))),
+                }
+            }
+        }
+        Ok(Report { rows, columns })
+    }
+}
+
+impl Report {
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Number of rows with a 1 and with a 0 in `column` among `rows`.
+    fn bit_count(&self, column: usize, rows: &RowSet) -> (usize, usize) {
+        let one_count = self.columns[column].intersection_len(rows);
+        (one_count, rows.len() - one_count)
+    }
+
+    /// Bits of `row`, most significant first.
+    pub fn bits(&self, row: usize) -> String {
+        self.columns
+            .iter()
+            .map(|c| if c.contains(row) { '1' } else { '0' })
+            .collect()
+    }
+
+    /// Value of `row`, as wide as the report.
+    pub fn value(&self, row: usize) -> BigUint {
+        from_bits(self.columns.iter().map(|c| c.contains(row)))
+    }
+
+    fn gamma_epsilon(&self) -> (BigUint, BigUint) {
+        let all = RowSet::full(self.rows);
+        let most_common: Vec<bool> = (0..self.width())
+            .map(|column| {
+                let (one_count, zero_count) = self.bit_count(column, &all);
+                one_count > zero_count
+            })
+            .collect();
+        (
+            from_bits(most_common.iter().copied()),
+            from_bits(most_common.iter().map(|&bit| !bit)),
+        )
+    }
+}
+
+/// Number written with `bits`, most significant first.
+fn from_bits(bits: impl Iterator<Item = bool>) -> BigUint {
+    bits.fold(BigUint::zero(), |acc, bit| {
+        acc.clone() + acc + BigUint::from(bit as u8)
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Commonness {
+    Most,
+    Least,
+}
+
+/// Keeps the numbers with the most or least common bit of a column, column after column
+/// from the left, until a single one is left. `tie` is the bit kept when both are as common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitCriteria {
+    pub keep: Commonness,
+    pub tie: bool,
+}
+
+impl BitCriteria {
+    pub const OXYGEN_GENERATOR: BitCriteria = BitCriteria {
+        keep: Commonness::Most,
+        tie: true,
+    };
+    pub const CO2_SCRUBBER: BitCriteria = BitCriteria {
+        keep: Commonness::Least,
+        tie: false,
+    };
+
+    fn kept_bit(&self, one_count: usize, zero_count: usize) -> bool {
+        if one_count == zero_count {
+            return self.tie;
+        }
+        match self.keep {
+            Commonness::Most => one_count > zero_count,
+            Commonness::Least => one_count < zero_count,
+        }
+    }
+
+    /// Row of the number selected in `report`, a column which would remove every number
+    /// is skipped and the first of several identical numbers is taken.
+    pub fn select(&self, report: &Report) -> usize {
+        let mut rows = RowSet::full(report.rows);
+        for column in 0..report.width() {
+            if rows.len() <= 1 {
+                break;
+            }
+            let (one_count, zero_count) = report.bit_count(column, &rows);
+            let bit = self.kept_bit(one_count, zero_count);
+            if (bit && one_count == 0) || (!bit && zero_count == 0) {
+                continue;
+            }
+            rows.retain(&report.columns[column], bit);
+        }
+        rows.first().unwrap_or(0)
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Input = Report;
+    type Part1 = BigUint;
+    type Part2 = BigUint;
+
+    fn part1(report: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        let (gamma, epsilon) = report.gamma_epsilon();
+        Ok(gamma * epsilon)
+    }
+
+    fn part2(report: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        let oxygen_generator_rating = report.value(BitCriteria::OXYGEN_GENERATOR.select(report));
+        let c02_scrubber_rating = report.value(BitCriteria::CO2_SCRUBBER.select(report));
+        Ok(c02_scrubber_rating * oxygen_generator_rating)
+    }
+}
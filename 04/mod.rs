@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid::{Grid, Position};
+use crate::parse::{self, ParseError, ParseErrorKind};
+use crate::solution::Solution;
+
+/// Bingo card with the marks counted per line, so a mark and a bingo check take constant time.
+#[derive(Clone)]
+pub struct Board {
+    nums: Grid<u32>,
+    marks: Grid<bool>,
+    /// Positions of each number on the board.
+    index: HashMap<u32, Vec<Position>>,
+    row_marks: Vec<usize>,
+    column_marks: Vec<usize>,
+    /// Marks on the diagonal from the top left and the one from the top right.
+    diagonal_marks: [usize; 2],
+    unmarked_sum: u64,
+}
+
+impl Board {
+    fn new(nums: Grid<u32>) -> Board {
+        let mut index: HashMap<u32, Vec<Position>> = HashMap::new();
+        for pos in nums.positions() {
+            index.entry(nums[pos]).or_default().push(pos);
+        }
+        Board {
+            marks: Grid::new(nums.width(), nums.height(), false),
+            index,
+            row_marks: vec![0; nums.height()],
+            column_marks: vec![0; nums.width()],
+            diagonal_marks: [0, 0],
+            unmarked_sum: nums.iter().map(|&n| n as u64).sum(),
+            nums,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.nums.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.nums.height()
+    }
+
+    /// Diagonals only exist on square boards.
+    fn has_diagonals(&self) -> bool {
+        self.width() == self.height()
+    }
+
+    /// Whether a full row or column is marked, or a full diagonal if `diagonals` is set.
+    pub fn bingo(&self, diagonals: bool) -> bool {
+        self.row_marks.iter().any(|&m| m == self.width())
+            || self.column_marks.iter().any(|&m| m == self.height())
+            || (diagonals
+                && self.has_diagonals()
+                && self.diagonal_marks.iter().any(|&m| m == self.width()))
+    }
+
+    pub fn mark(&mut self, num: u32) {
+        let Some(positions) = self.index.get(&num) else {
+            return;
+        };
+        for &(y, x) in positions {
+            if self.marks[(y, x)] {
+                continue;
+            }
+            self.marks[(y, x)] = true;
+            self.unmarked_sum -= num as u64;
+            self.row_marks[y] += 1;
+            self.column_marks[x] += 1;
+            if self.has_diagonals() {
+                if y == x {
+                    self.diagonal_marks[0] += 1;
+                }
+                if y + x == self.width() - 1 {
+                    self.diagonal_marks[1] += 1;
+                }
+            }
+        }
+    }
+
+    /// `None` if the score doesn't fit in 64 bits.
+    pub fn score(&self, num: u32) -> Option<u64> {
+        self.unmarked_sum.checked_mul(num as u64)
+    }
+
+    /// Positions of every row and column, and of the diagonals if `diagonals` is set.
+    fn lines(&self, diagonals: bool) -> Vec<Vec<Position>> {
+        let (width, height) = (self.width(), self.height());
+        let mut lines: Vec<Vec<Position>> = (0..height)
+            .map(|y| (0..width).map(|x| (y, x)).collect())
+            .collect();
+        lines.extend((0..width).map(|x| (0..height).map(|y| (y, x)).collect()));
+        if diagonals && self.has_diagonals() {
+            lines.push((0..width).map(|i| (i, i)).collect());
+            lines.push((0..width).map(|i| (i, width - 1 - i)).collect());
+        }
+        lines
+    }
+
+    /// Numbers of the fully marked lines, each number once.
+    fn completed_numbers(&self, diagonals: bool) -> Vec<u32> {
+        let mut nums: Vec<u32> = self
+            .lines(diagonals)
+            .into_iter()
+            .filter(|l| l.iter().all(|&pos| self.marks[pos]))
+            .flatten()
+            .map(|pos| self.nums[pos])
+            .collect();
+        nums.sort();
+        nums.dedup();
+        nums
+    }
+
+    /// Unmarked numbers of the line closest to a bingo, the first such line if several are.
+    fn closest_line(&self, diagonals: bool) -> Vec<u32> {
+        self.lines(diagonals)
+            .into_iter()
+            .map(|l| {
+                l.into_iter()
+                    .filter(|&pos| !self.marks[pos])
+                    .map(|pos| self.nums[pos])
+                    .collect::<Vec<u32>>()
+            })
+            .min_by_key(|missing| missing.len())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<Vec<u32>> = s
+            .lines()
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|n| parse::number(s, n))
+                    .collect::<Result<_, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+        let width = rows.first().map_or(0, |r| r.len());
+        if width == 0 {
+            return Err(ParseError::at(s, s, ParseErrorKind::Empty));
+        }
+        if let Some((row, l)) = rows.iter().zip(s.lines()).find(|(r, _)| r.len() != width) {
+            return Err(ParseError::at(
+                s,
+                l,
+                ParseErrorKind::Invalid(format!(
+                    "row has {} numbers instead of {}",
+                    row.len(),
+                    width
+                )),
+            ));
+        }
+        Ok(Board::new(Grid::from_rows(rows).unwrap()))
+    }
+}
+
+/// Board `board` won when `number`, the draw `draw` (from 0), was drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    pub draw: usize,
+    pub number: u32,
+    /// `None` if it doesn't fit in 64 bits.
+    pub score: Option<u64>,
+}
+
+impl Win {
+    pub fn score(&self) -> Result<u64, Box<dyn Error>> {
+        Ok(self
+            .score
+            .ok_or_else(|| format!("score of board {} doesn't fit in 64 bits", self.board + 1))?)
+    }
+}
+
+/// Bingo played draw after draw, yields the boards as they win,
+/// boards winning on the same draw in their order.
+pub struct Game<'a> {
+    draws: &'a [u32],
+    boards: Vec<Board>,
+    diagonals: bool,
+    won: Vec<bool>,
+    draw: usize,
+    /// Boards which won on the last draw and aren't yielded yet, in reverse order.
+    pending: Vec<Win>,
+}
+
+impl Iterator for Game<'_> {
+    type Item = Win;
+
+    fn next(&mut self) -> Option<Win> {
+        while self.pending.is_empty() {
+            let &number = self.draws.get(self.draw)?;
+            for (i, board) in self.boards.iter_mut().enumerate() {
+                if self.won[i] {
+                    continue;
+                }
+                board.mark(number);
+                if board.bingo(self.diagonals) {
+                    self.won[i] = true;
+                    self.pending.push(Win {
+                        board: i,
+                        draw: self.draw,
+                        number,
+                        score: board.score(number),
+                    });
+                }
+            }
+            self.pending.reverse();
+            self.draw += 1;
+        }
+        self.pending.pop()
+    }
+}
+
+pub struct Bingo {
+    draws: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+impl FromStr for Bingo {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first_line, rest) = parse::split_once(s, s, "\n")?;
+        let draws = parse::separated(s, first_line, ",")?;
+        let boards = rest
+            .trim()
+            .split("\n\n")
+            .map(|b| b.parse().map_err(|e: ParseError| e.within(s, b)))
+            .collect::<Result<_, _>>()?;
+        Ok(Bingo { draws, boards })
+    }
+}
+
+impl Bingo {
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    pub fn play(&self, diagonals: bool) -> Game<'_> {
+        Game {
+            draws: &self.draws,
+            boards: self.boards.clone(),
+            diagonals,
+            won: vec![false; self.boards.len()],
+            draw: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Every board with the draw it wins on, its score and the numbers which made it win.
+    pub fn analyse(&self, diagonals: bool) -> Result<Analysis, Box<dyn Error>> {
+        let mut ranking: Vec<BoardAnalysis> = self
+            .play(diagonals)
+            .map(|win| {
+                win.score()?;
+                let mut board = self.boards[win.board].clone();
+                self.draws[..=win.draw].iter().for_each(|&n| board.mark(n));
+                let mut decisive = board.completed_numbers(diagonals);
+                decisive.sort_by_key(|n| self.draws.iter().position(|d| d == n));
+                Ok(BoardAnalysis {
+                    board: win.board,
+                    win: Some(win),
+                    decisive,
+                })
+            })
+            .collect::<Result<_, Box<dyn Error>>>()?;
+        let mut never_won: Vec<usize> = (0..self.boards.len()).collect();
+        never_won.retain(|&i| ranking.iter().all(|a| a.board != i));
+        ranking.extend(never_won.into_iter().map(|board| BoardAnalysis {
+            board,
+            win: None,
+            decisive: Vec::new(),
+        }));
+        Ok(Analysis(ranking))
+    }
+
+    /// Every board after `draws` instead of the draws of the input.
+    pub fn what_if(&self, draws: &[u32], diagonals: bool) -> WhatIf {
+        let mut boards = self.boards.clone();
+        let mut won = vec![None; boards.len()];
+        for (draw, &number) in draws.iter().enumerate() {
+            for (i, board) in boards.iter_mut().enumerate() {
+                if won[i].is_none() {
+                    board.mark(number);
+                    if board.bingo(diagonals) {
+                        won[i] = Some(draw);
+                    }
+                }
+            }
+        }
+        let mut outlooks: Vec<Outlook> = boards
+            .iter()
+            .zip(won)
+            .enumerate()
+            .map(|(i, (board, won))| Outlook {
+                board: i,
+                won,
+                missing: match won {
+                    Some(_) => Vec::new(),
+                    None => board.closest_line(diagonals),
+                },
+            })
+            .collect();
+        outlooks.sort_by_key(|o| (o.won.is_none(), o.won, o.missing.len()));
+        WhatIf {
+            draws: draws.to_vec(),
+            boards: outlooks,
+        }
+    }
+}
+
+/// How a board fares over the whole draw sequence.
+#[derive(Debug, Clone)]
+pub struct BoardAnalysis {
+    pub board: usize,
+    /// `None` if the board never wins.
+    pub win: Option<Win>,
+    /// Numbers of the lines completed by the winning draw in the order they were drawn,
+    /// the winning number last.
+    pub decisive: Vec<u32>,
+}
+
+/// Boards ranked by the draw they win on, then by board, boards which never win last.
+#[derive(Debug)]
+pub struct Analysis(pub Vec<BoardAnalysis>);
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rank board  turn number  score decisive")?;
+        for (rank, analysis) in self.0.iter().enumerate() {
+            write!(f, "\n{:>4} {:>5} ", rank + 1, analysis.board + 1)?;
+            match analysis.win {
+                Some(win) => write!(
+                    f,
+                    "{:>5} {:>6} {:>6} {}",
+                    win.draw + 1,
+                    win.number,
+                    win.score.unwrap_or_default(),
+                    analysis
+                        .decisive
+                        .iter()
+                        .map(|n| n.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                )?,
+                None => write!(f, "{:>5} {:>6} {:>6} never wins", "-", "-", "-")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Where a board stands after a partial draw sequence.
+#[derive(Debug, Clone)]
+pub struct Outlook {
+    pub board: usize,
+    /// Draw (from 0) the board won on, `None` if it can still win.
+    pub won: Option<usize>,
+    /// Numbers the board still needs for its closest line, empty once it won.
+    /// Numbers already drawn are marked, so as many further draws are the fewest it can win in.
+    pub missing: Vec<u32>,
+}
+
+/// Boards after the draws `draws`, the boards which won first in the order they did,
+/// then the others from the closest to a bingo.
+#[derive(Debug)]
+pub struct WhatIf {
+    pub draws: Vec<u32>,
+    pub boards: Vec<Outlook>,
+}
+
+impl fmt::Display for WhatIf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "after {} draws", self.draws.len())?;
+        for outlook in &self.boards {
+            match outlook.won {
+                Some(draw) => write!(
+                    f,
+                    "\nboard {}: won on turn {} with {}",
+                    outlook.board + 1,
+                    draw + 1,
+                    self.draws[draw]
+                )?,
+                None => write!(
+                    f,
+                    "\nboard {}: can win in {} more draw{} with {}",
+                    outlook.board + 1,
+                    outlook.missing.len(),
+                    if outlook.missing.len() == 1 { "" } else { "s" },
+                    outlook
+                        .missing
+                        .iter()
+                        .map(|n| n.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Input = Bingo;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        let first = input.play(false).next().ok_or("no board won")?;
+        first.score()
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        let wins: Vec<Win> = input.play(false).collect();
+        if wins.len() != input.boards.len() {
+            Err("not every board won")?;
+        }
+        wins.last().ok_or("no board")?.score()
+    }
+}
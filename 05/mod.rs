@@ -0,0 +1,577 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::iter;
+use std::str::FromStr;
+
+use crate::grid::{Grid, Position};
+use crate::image::{self, ColourMap, Image, RED};
+use crate::parse::{self, ParseError};
+use crate::solution::{Lines, Solution};
+
+/// `(x, y)` coordinates of the ocean floor.
+pub type Point = (i32, i32);
+
+pub struct Segment {
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Horizontal,
+    Vertical,
+    /// At exactly 45 degrees.
+    Diagonal,
+    /// Any other slope.
+    Sloped,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Horizontal,
+        Direction::Vertical,
+        Direction::Diagonal,
+        Direction::Sloped,
+    ];
+}
+
+impl Segment {
+    pub fn direction(&self) -> Direction {
+        use Direction::*;
+        if self.y1 == self.y2 {
+            Horizontal
+        } else if self.x1 == self.x2 {
+            Vertical
+        } else if (self.x2 as i64 - self.x1 as i64).abs() == (self.y2 as i64 - self.y1 as i64).abs()
+        {
+            Diagonal
+        } else {
+            Sloped
+        }
+    }
+
+    /// Number of points the segment covers.
+    pub fn point_count(&self) -> usize {
+        (self.x2 as i64 - self.x1 as i64)
+            .abs()
+            .max((self.y2 as i64 - self.y1 as i64).abs()) as usize
+            + 1
+    }
+
+    /// Points from the first end to the second one, the closest to the line for any
+    /// slope (Bresenham), one per column or row whichever the segment spans more of.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let (x2, y2) = (self.x2, self.y2);
+        // in i64, the ends can be as far apart as the range of i32
+        let (dx, dy) = (x2 as i64 - self.x1 as i64, y2 as i64 - self.y1 as i64);
+        let (step_x, step_y) = (dx.signum() as i32, dy.signum() as i32);
+        let (dx, dy) = (dx.abs(), -dy.abs());
+        let mut error = dx + dy;
+        let mut next = Some((self.x1, self.y1));
+        iter::from_fn(move || {
+            let (x, y) = next?;
+            next = if (x, y) == (x2, y2) {
+                None
+            } else {
+                let (mut x, mut y) = (x, y);
+                let twice = 2 * error;
+                if twice >= dy {
+                    error += dy;
+                    x += step_x;
+                }
+                if twice <= dx {
+                    error += dx;
+                    y += step_y;
+                }
+                Some((x, y))
+            };
+            Some((x, y))
+        })
+    }
+}
+
+impl FromStr for Segment {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (xy1, xy2) = parse::split_once(s, s, " -> ")?;
+        let (x1, y1) = parse::split_once(s, xy1, ",")?;
+        let (x2, y2) = parse::split_once(s, xy2, ",")?;
+        let x1 = parse::number(s, x1)?;
+        let y1 = parse::number(s, y1)?;
+        let x2 = parse::number(s, x2)?;
+        let y2 = parse::number(s, y2)?;
+        Ok(Segment { x1, y1, x2, y2 })
+    }
+}
+
+/// Rectangle of points, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Region {
+    /// Region between two opposite corners, in any order.
+    pub fn new((x1, y1): Point, (x2, y2): Point) -> Region {
+        Region {
+            min: (x1.min(x2), y1.min(y2)),
+            max: (x1.max(x2), y1.max(y2)),
+        }
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        (self.min.0..=self.max.0).contains(&x) && (self.min.1..=self.max.1).contains(&y)
+    }
+
+    /// Computed in `i64`, a region can span the whole range of `i32`.
+    pub fn width(&self) -> usize {
+        (self.max.0 as i64 - self.min.0 as i64 + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.1 as i64 - self.min.1 as i64 + 1) as usize
+    }
+
+    pub fn area(&self) -> usize {
+        self.width().saturating_mul(self.height())
+    }
+
+    /// Position of `point` in a grid over the region.
+    fn offset(&self, (x, y): Point) -> Position {
+        (
+            (y as i64 - self.min.1 as i64) as usize,
+            (x as i64 - self.min.0 as i64) as usize,
+        )
+    }
+
+    /// Points of both regions, `None` if they don't overlap.
+    pub fn intersection(&self, other: &Region) -> Option<Region> {
+        let min = (self.min.0.max(other.min.0), self.min.1.max(other.min.1));
+        let max = (self.max.0.min(other.max.0), self.max.1.min(other.max.1));
+        (min.0 <= max.0 && min.1 <= max.1).then_some(Region { min, max })
+    }
+
+    /// Smallest region containing both.
+    pub fn union(&self, other: &Region) -> Region {
+        Region {
+            min: (self.min.0.min(other.min.0), self.min.1.min(other.min.1)),
+            max: (self.max.0.max(other.max.0), self.max.1.max(other.max.1)),
+        }
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.min.1..=self.max.1).flat_map(move |y| (self.min.0..=self.max.0).map(move |x| (x, y)))
+    }
+}
+
+/// How the lines covering each point are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counter {
+    /// A hash map of the covered points, for lines spread far apart.
+    Sparse,
+    /// An array over the bounding box of the lines.
+    Dense,
+}
+
+/// A dense counter is used while the bounding box has at most this many points per point
+/// covered by a line, an array cell being several times smaller than a hash map entry.
+const DENSE_MAX_FILL: usize = 8;
+
+impl Counter {
+    /// Dense unless most of the bounding box of `segments` would be empty.
+    pub fn for_segments(segments: &[&Segment]) -> Counter {
+        let covered: usize = segments.iter().map(|s| s.point_count()).sum();
+        match bounding_box(segments) {
+            Some(b) if b.area() <= covered.saturating_mul(DENSE_MAX_FILL) => Counter::Dense,
+            _ => Counter::Sparse,
+        }
+    }
+}
+
+fn bounding_box(segments: &[&Segment]) -> Option<Region> {
+    segments
+        .iter()
+        .map(|s| Region::new((s.x1, s.y1), (s.x2, s.y2)))
+        .reduce(|a, b| a.union(&b))
+}
+
+enum Counts {
+    Sparse(HashMap<Point, u32>),
+    Dense { bounds: Region, counts: Grid<u32> },
+}
+
+/// Number of lines covering each point.
+pub struct Overlaps {
+    counts: Counts,
+}
+
+impl Overlaps {
+    /// Overlaps of the `segments` going in one of the `directions`, with the counter
+    /// suiting them.
+    pub fn new(segments: &[Segment], directions: &[Direction]) -> Overlaps {
+        let segments: Vec<&Segment> = segments
+            .iter()
+            .filter(|s| directions.contains(&s.direction()))
+            .collect();
+        Overlaps::with_counter(&segments, Counter::for_segments(&segments))
+    }
+
+    pub fn with_counter(segments: &[&Segment], counter: Counter) -> Overlaps {
+        let counts = match counter {
+            Counter::Sparse => {
+                let mut counts: HashMap<Point, u32> = HashMap::new();
+                for p in segments.iter().flat_map(|s| s.positions()) {
+                    *counts.entry(p).or_insert(0) += 1;
+                }
+                Counts::Sparse(counts)
+            }
+            Counter::Dense => {
+                let bounds = bounding_box(segments).unwrap_or(Region::new((0, 0), (0, 0)));
+                let mut counts = Grid::new(bounds.width(), bounds.height(), 0);
+                for p in segments.iter().flat_map(|s| s.positions()) {
+                    counts[bounds.offset(p)] += 1;
+                }
+                Counts::Dense { bounds, counts }
+            }
+        };
+        Overlaps { counts }
+    }
+
+    pub fn counter(&self) -> Counter {
+        match self.counts {
+            Counts::Sparse(_) => Counter::Sparse,
+            Counts::Dense { .. } => Counter::Dense,
+        }
+    }
+
+    /// Number of lines covering `point`.
+    pub fn at(&self, point: Point) -> u32 {
+        match &self.counts {
+            Counts::Sparse(counts) => counts.get(&point).copied().unwrap_or(0),
+            Counts::Dense { bounds, counts } => {
+                if !bounds.contains(point) {
+                    return 0;
+                }
+                counts[bounds.offset(point)]
+            }
+        }
+    }
+
+    /// Number of points of `region` covered by at least `lines` lines, `lines` being at least 1.
+    pub fn count_in(&self, region: &Region, lines: u32) -> usize {
+        let lines = lines.max(1);
+        match &self.counts {
+            Counts::Sparse(counts) if counts.len() < region.area() => counts
+                .iter()
+                .filter(|&(&p, &c)| c >= lines && region.contains(p))
+                .count(),
+            Counts::Sparse(_) => region.points().filter(|&p| self.at(p) >= lines).count(),
+            Counts::Dense { bounds, .. } => match region.intersection(bounds) {
+                Some(region) => region.points().filter(|&p| self.at(p) >= lines).count(),
+                None => 0,
+            },
+        }
+    }
+
+    /// Smallest region with every covered point, `None` without lines.
+    pub fn bounds(&self) -> Option<Region> {
+        match &self.counts {
+            Counts::Sparse(counts) => counts
+                .keys()
+                .map(|&p| Region::new(p, p))
+                .reduce(|a, b| a.union(&b)),
+            Counts::Dense { bounds, .. } => Some(*bounds),
+        }
+    }
+
+    /// One pixel per point of the bounds, the top left one at the smallest coordinates,
+    /// brighter where more lines overlap and the dangerous points in red.
+    pub fn image(&self) -> Result<Image, Box<dyn Error>> {
+        let bounds = self.bounds().ok_or("no lines to draw")?;
+        image::check_size(bounds.width(), bounds.height())?;
+        let counts = Grid::from_fn(bounds.width(), bounds.height(), |(y, x)| {
+            self.at((bounds.min.0 + x as i32, bounds.min.1 + y as i32))
+        });
+        let mut image = Image::from_values(&counts, ColourMap::Grey);
+        image.highlight_where(|p| counts[p] >= 2, RED, 0.6);
+        Ok(image)
+    }
+
+    /// Number of points covered by at least 2 lines.
+    pub fn dangerous(&self) -> usize {
+        match &self.counts {
+            Counts::Sparse(counts) => counts.values().filter(|&&c| c >= 2).count(),
+            Counts::Dense { counts, .. } => counts.iter().filter(|&&c| c >= 2).count(),
+        }
+    }
+}
+
+/// Cross product of two vectors, wide enough for any two differences of `i32` points.
+fn cross(u: (i64, i64), v: (i64, i64)) -> i128 {
+    u.0 as i128 * v.1 as i128 - u.1 as i128 * v.0 as i128
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Line through lattice points: the smallest step between two of them, going right or
+/// else down, and the cross product of that step with any of the points.
+type Line = ((i64, i64), i128);
+
+/// Lattice points of a segment, `start + k * step` for `k` in `0..=steps`.
+#[derive(Debug, Clone, Copy)]
+struct Lattice {
+    start: (i64, i64),
+    step: (i64, i64),
+    steps: i64,
+}
+
+/// What two segments have in common.
+enum Crossing {
+    Point((i64, i64)),
+    /// Lattice points from `start` to `end` on a line, as coordinates along the line.
+    Overlap(Line, i64, i64),
+}
+
+impl Lattice {
+    fn new(segment: &Segment) -> Lattice {
+        let (mut a, mut b) = (
+            (segment.x1 as i64, segment.y1 as i64),
+            (segment.x2 as i64, segment.y2 as i64),
+        );
+        if (b.0, b.1) < (a.0, a.1) {
+            (a, b) = (b, a);
+        }
+        let steps = gcd((b.0 - a.0).abs(), (b.1 - a.1).abs());
+        let step = match steps {
+            // any step does for a single point
+            0 => (1, 0),
+            _ => ((b.0 - a.0) / steps, (b.1 - a.1) / steps),
+        };
+        Lattice {
+            start: a,
+            step,
+            steps,
+        }
+    }
+
+    fn line(&self) -> Line {
+        (self.step, cross(self.step, self.start))
+    }
+
+    fn end(&self) -> (i64, i64) {
+        (
+            self.start.0 + self.steps * self.step.0,
+            self.start.1 + self.steps * self.step.1,
+        )
+    }
+
+    fn contains(&self, p: (i64, i64)) -> bool {
+        let offset = (p.0 - self.start.0, p.1 - self.start.1);
+        let (along, stride) = (along(self.step, offset), stride(self.step));
+        cross(self.step, offset) == 0
+            && along % stride == 0
+            && (0..=self.steps).contains(&(along / stride))
+    }
+
+    fn crossing(&self, other: &Lattice) -> Option<Crossing> {
+        if self.step == other.step {
+            let line = self.line();
+            if line != other.line() {
+                return None;
+            }
+            let start = along(self.step, self.start).max(along(self.step, other.start));
+            let end = along(self.step, self.end()).min(along(self.step, other.end()));
+            return (start <= end).then_some(Crossing::Overlap(line, start, end));
+        }
+        // start + s * step == other.start + t * other.step
+        let denominator = cross(self.step, other.step);
+        if denominator == 0 {
+            return None;
+        }
+        let offset = (other.start.0 - self.start.0, other.start.1 - self.start.1);
+        let s = cross(offset, other.step);
+        let t = cross(offset, self.step);
+        if s % denominator != 0 || t % denominator != 0 {
+            return None;
+        }
+        let (s, t) = (s / denominator, t / denominator);
+        if !(0..=self.steps as i128).contains(&s) || !(0..=other.steps as i128).contains(&t) {
+            return None;
+        }
+        let s = s as i64;
+        Some(Crossing::Point((
+            self.start.0 + s * self.step.0,
+            self.start.1 + s * self.step.1,
+        )))
+    }
+}
+
+/// Coordinate of `p` along lines going by `step`.
+fn along(step: (i64, i64), p: (i64, i64)) -> i64 {
+    if step.0 != 0 {
+        p.0
+    } else {
+        p.1
+    }
+}
+
+/// Difference of coordinates along lines going by `step` between two lattice points
+/// next to each other.
+fn stride(step: (i64, i64)) -> i64 {
+    along(step, step)
+}
+
+/// Lattice point of `line` at the coordinate `along` it.
+fn point_on(((dx, dy), c): Line, along: i64) -> (i64, i64) {
+    if dx != 0 {
+        (
+            along,
+            ((c + dy as i128 * along as i128) / dx as i128) as i64,
+        )
+    } else {
+        ((-c / dy as i128) as i64, along)
+    }
+}
+
+/// Overlaps of segments found by intersecting every pair of them instead of going
+/// through their points, so coordinates can be as large as `i32` allows.
+///
+/// Only the lattice points exactly on a segment count, which are the points rasterised
+/// for horizontal, vertical and diagonal segments but not for the other slopes.
+pub struct Intersections {
+    segments: Vec<Lattice>,
+    /// Points where two segments cross without overlapping.
+    points: HashSet<(i64, i64)>,
+    /// Parts of a line covered by at least two segments, merged and sorted.
+    overlaps: HashMap<Line, Vec<(i64, i64)>>,
+}
+
+impl Intersections {
+    pub fn new(segments: &[Segment], directions: &[Direction]) -> Intersections {
+        let segments: Vec<Lattice> = segments
+            .iter()
+            .filter(|s| directions.contains(&s.direction()))
+            .map(Lattice::new)
+            .collect();
+        let mut points = HashSet::new();
+        let mut overlaps: HashMap<Line, Vec<(i64, i64)>> = HashMap::new();
+        for (i, a) in segments.iter().enumerate() {
+            for b in &segments[i + 1..] {
+                match a.crossing(b) {
+                    Some(Crossing::Point(p)) => {
+                        points.insert(p);
+                    }
+                    Some(Crossing::Overlap(line, start, end)) => {
+                        overlaps.entry(line).or_default().push((start, end))
+                    }
+                    None => (),
+                }
+            }
+        }
+        for ranges in overlaps.values_mut() {
+            ranges.sort();
+            let mut merged: Vec<(i64, i64)> = Vec::with_capacity(ranges.len());
+            for &(start, end) in ranges.iter() {
+                match merged.last_mut() {
+                    Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                    _ => merged.push((start, end)),
+                }
+            }
+            *ranges = merged;
+        }
+        Intersections {
+            segments,
+            points,
+            overlaps,
+        }
+    }
+
+    /// Number of segments going through `point`.
+    pub fn at(&self, (x, y): Point) -> usize {
+        self.segments
+            .iter()
+            .filter(|s| s.contains((x as i64, y as i64)))
+            .count()
+    }
+
+    /// Whether `p` is in an overlap of one of the lines going by `steps`.
+    fn in_overlap(&self, p: (i64, i64), steps: &[(i64, i64)]) -> bool {
+        steps.iter().any(|&step| {
+            self.overlaps
+                .get(&(step, cross(step, p)))
+                .is_some_and(|ranges| {
+                    let along = along(step, p);
+                    let i = ranges.partition_point(|&(_, end)| end < along);
+                    ranges.get(i).is_some_and(|&(start, _)| start <= along)
+                })
+        })
+    }
+
+    /// Number of points covered by at least 2 segments: the points of the overlaps,
+    /// less the repeats where overlaps on different lines cross, and the crossings
+    /// outside of the overlaps.
+    pub fn dangerous(&self) -> usize {
+        let mut count = 0;
+        let mut ranges: Vec<Lattice> = Vec::new();
+        for (&line, line_ranges) in &self.overlaps {
+            let stride = stride(line.0);
+            for &(start, end) in line_ranges {
+                count += ((end - start) / stride + 1) as usize;
+                ranges.push(Lattice {
+                    start: point_on(line, start),
+                    step: line.0,
+                    steps: (end - start) / stride,
+                });
+            }
+        }
+        let mut repeats: HashMap<(i64, i64), usize> = HashMap::new();
+        for (i, a) in ranges.iter().enumerate() {
+            for b in &ranges[i + 1..] {
+                if let Some(Crossing::Point(p)) = a.crossing(b) {
+                    *repeats.entry(p).or_insert(0) += 1;
+                }
+            }
+        }
+        // a point on m overlaps is counted m times and crossed by m * (m - 1) / 2 pairs
+        for &pairs in repeats.values() {
+            let m = (1..).find(|m| m * (m - 1) / 2 == pairs).unwrap_or(1);
+            count -= m - 1;
+        }
+        let mut steps: Vec<(i64, i64)> = self.overlaps.keys().map(|&(step, _)| step).collect();
+        steps.sort();
+        steps.dedup();
+        count
+            + self
+                .points
+                .iter()
+                .filter(|&&p| !self.in_overlap(p, &steps))
+                .count()
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Lines<Segment>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(Overlaps::new(&input.0, &[Direction::Horizontal, Direction::Vertical]).dangerous())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(Overlaps::new(&input.0, &Direction::ALL).dangerous())
+    }
+}
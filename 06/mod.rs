@@ -0,0 +1,197 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::number::Unsigned;
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+/// Ages of the fishes, in days until they give birth.
+#[derive(Clone)]
+pub struct LanternFishPool(Vec<usize>);
+
+impl FromStr for LanternFishPool {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(LanternFishPool(parse::separated(s, s.trim(), ",")?))
+    }
+}
+
+/// A fish at age 0 gives birth and starts again from `reset_age`,
+/// its newborn starts from `newborn_age`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lifecycle {
+    pub reset_age: usize,
+    pub newborn_age: usize,
+}
+
+impl Lifecycle {
+    pub const LANTERNFISH: Lifecycle = Lifecycle {
+        reset_age: 6,
+        newborn_age: 8,
+    };
+
+    /// Number of ages a fish can have.
+    fn ages(&self) -> usize {
+        self.reset_age.max(self.newborn_age) + 1
+    }
+
+    /// Matrix taking the number of fishes of each age to the numbers on the next day.
+    fn transition<N: Unsigned>(&self) -> Matrix<N> {
+        let ages = self.ages();
+        let mut m = vec![vec![0u8; ages]; ages];
+        for age in 1..ages {
+            m[age - 1][age] = 1;
+        }
+        m[self.reset_age][0] += 1;
+        m[self.newborn_age][0] += 1;
+        Matrix(
+            m.into_iter()
+                .map(|row| row.into_iter().map(N::from).collect())
+                .collect(),
+        )
+    }
+}
+
+/// Square matrix whose operations saturate at the largest `N`, if there's one.
+#[derive(Debug, Clone)]
+struct Matrix<N>(Vec<Vec<N>>);
+
+fn dot<'a, N: Unsigned + 'a>(a: impl Iterator<Item = &'a N>, b: impl Iterator<Item = &'a N>) -> N {
+    a.zip(b).fold(N::from(0), |acc, (a, b)| {
+        acc.saturating_add(&a.saturating_mul(b))
+    })
+}
+
+impl<N: Unsigned> Matrix<N> {
+    fn mul(&self, other: &Matrix<N>) -> Matrix<N> {
+        Matrix(
+            self.0
+                .iter()
+                .map(|row| {
+                    (0..other.0.len())
+                        .map(|j| dot(row.iter(), other.0.iter().map(|r| &r[j])))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    fn apply(&self, v: &[N]) -> Vec<N> {
+        self.0.iter().map(|row| dot(row.iter(), v.iter())).collect()
+    }
+}
+
+fn total<N: Unsigned>(counts: &[N]) -> N {
+    counts
+        .iter()
+        .fold(N::from(0), |acc, c| acc.saturating_add(c))
+}
+
+/// Number of fishes of each age, the days being counted by raising the transition
+/// matrix to their power so that any number of days takes about log2(days) products.
+/// Counted in `N`, `BigUint` for populations that don't fit in 128 bits.
+#[derive(Debug, Clone)]
+pub struct Population<N = u128> {
+    transition: Matrix<N>,
+    counts: Vec<N>,
+}
+
+impl<N: Unsigned> Population<N> {
+    pub fn new(
+        pool: &LanternFishPool,
+        lifecycle: Lifecycle,
+    ) -> Result<Population<N>, Box<dyn Error>> {
+        let mut counts = vec![N::from(0); lifecycle.ages()];
+        for &age in &pool.0 {
+            let count = counts
+                .get_mut(age)
+                .ok_or_else(|| format!("fish age {} is more than {}", age, lifecycle.ages() - 1))?;
+            *count = count.saturating_add(&N::from(1));
+        }
+        Ok(Population {
+            transition: lifecycle.transition(),
+            counts,
+        })
+    }
+
+    pub fn size(&self) -> N {
+        total(&self.counts)
+    }
+
+    /// Number of fishes after `days` days.
+    pub fn after(&self, days: u64) -> Result<N, Box<dyn Error>> {
+        let mut counts = self.counts.clone();
+        let mut power = self.transition.clone();
+        let mut days = days;
+        while days > 0 {
+            if days & 1 == 1 {
+                counts = power.apply(&counts);
+            }
+            days >>= 1;
+            if days > 0 {
+                power = power.mul(&power);
+            }
+        }
+        let size = total(&counts);
+        if size.is_max() {
+            Err(format!(
+                "population doesn't fit in {}",
+                std::any::type_name::<N>()
+            ))?;
+        }
+        Ok(size)
+    }
+
+    /// First day after which there are more than `threshold` fishes, 0 if there already
+    /// are, `None` if there are no fishes to ever get there.
+    pub fn first_day_above(&self, threshold: &N) -> Result<Option<u64>, Box<dyn Error>> {
+        if threshold.is_max() {
+            Err(format!(
+                "the population can't be counted above {}",
+                std::any::type_name::<N>()
+            ))?;
+        }
+        if self.size() > *threshold {
+            return Ok(Some(0));
+        }
+        if self.size() == N::from(0) {
+            return Ok(None);
+        }
+        // powers[k] is the transition over 2^k days, the population grows at least
+        // once per cycle so the doubling ends, saturated counts being above the threshold
+        let mut powers = vec![self.transition.clone()];
+        while total(&powers[powers.len() - 1].apply(&self.counts)) <= *threshold {
+            let last = &powers[powers.len() - 1];
+            powers.push(last.mul(last));
+        }
+        // largest number of days still at most the threshold, then one more day
+        let mut counts = self.counts.clone();
+        let mut days = 0u64;
+        for (k, power) in powers.iter().enumerate().rev() {
+            let next = power.apply(&counts);
+            if total(&next) <= *threshold {
+                counts = next;
+                days += 1 << k;
+            }
+        }
+        Ok(Some(days + 1))
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Input = LanternFishPool;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        Population::new(input, Lifecycle::LANTERNFISH)?.after(80)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        Population::new(input, Lifecycle::LANTERNFISH)?.after(256)
+    }
+}
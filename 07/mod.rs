@@ -0,0 +1,74 @@
+use std::error::Error;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Clone)]
+pub struct Crabs(Vec<i32>);
+
+impl Crabs {
+    fn fuel_cost_part1(&self, pos: i32) -> i32 {
+        self.0.iter().map(|p| (p - pos).abs()).sum()
+    }
+
+    /*
+     * PERF: can probably replace the summation in map by a smarter formula
+     *       right now, we need -O3, it taks 45s with -O0
+     * f(x) = f(x - 1) + 1
+     * f(1) = 1
+     * Indeed: it's just the triangular numbers (thx Maxime)
+     */
+    fn fuel_cost_part2(&self, pos: i32) -> i32 {
+        self.0
+            .iter()
+            // previous solution in O(n^2): .map(|p| (1..(p - pos).abs() + 1).sum::<i32>())
+            .map(|p| Crabs::triangular((p - pos).abs()))
+            .sum()
+    }
+
+    fn positions(&self) -> Result<Range<i32>, Box<dyn Error>> {
+        let min = *self.0.iter().min().ok_or("no crabs pos")?;
+        let max = *self.0.iter().max().ok_or("no crabs pos")?;
+        Ok(min..max)
+    }
+
+    fn triangular(n: i32) -> i32 {
+        let n = n + 1;
+        (n * (n - 1)) / 2
+    }
+}
+
+impl FromStr for Crabs {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Crabs(parse::separated(s, s.trim(), ",")?))
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Crabs;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn part1(crabs: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(crabs
+            .positions()?
+            .map(|pos| crabs.fuel_cost_part1(pos))
+            .min()
+            .ok_or("couldn't find min fuel cost")?)
+    }
+
+    fn part2(crabs: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(crabs
+            .positions()?
+            .map(|pos| crabs.fuel_cost_part2(pos))
+            .min()
+            .ok_or("couldn't find min fuel cost")?)
+    }
+}
@@ -0,0 +1,439 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError, ParseErrorKind};
+use crate::solution::{Lines, Solution};
+
+#[derive(Debug)]
+struct Signal(Vec<char>);
+#[derive(Debug)]
+struct Signals(Vec<Signal>);
+
+#[derive(Debug)]
+pub struct Entry {
+    patterns: Signals,
+    outputs: Signals,
+}
+
+impl FromStr for Signal {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::at(s, s, ParseErrorKind::Missing("signal")));
+        }
+        Ok(Signal(s.chars().collect()))
+    }
+}
+
+impl FromStr for Signals {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Signals(
+            s.split(' ')
+                .map(|signal| signal.parse().map_err(|e: ParseError| e.within(s, signal)))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (patterns, outputs) = parse::split_once(s, s, " | ")?;
+        Ok(Entry {
+            patterns: patterns
+                .parse()
+                .map_err(|e: ParseError| e.within(s, patterns))?,
+            outputs: outputs
+                .parse()
+                .map_err(|e: ParseError| e.within(s, outputs))?,
+        })
+    }
+}
+
+/// Digits of the puzzle, segment `a` is the top one and `g` the bottom one.
+pub const SEVEN_SEGMENT_DIGITS: &str = "\
+0 abcefg
+1 cf
+2 acdeg
+3 acdfg
+4 bcdf
+5 abdfg
+6 abdefg
+7 acf
+8 abcdefg
+9 abcdfg
+";
+
+/// Set of segments or wires, bit `i` standing for the `i`th segment of the display.
+type Segments = u32;
+
+/// Symbols a display can show and the segments lit for each of them.
+///
+/// Parsed from one `<symbol> <lit segments>` per line, for example `7 acf`,
+/// lines starting with `#` are comments. The segments are every letter used
+/// in the table, wires have the same names as the segments they're meant to light.
+#[derive(Debug, Clone)]
+pub struct SegmentDisplay {
+    segments: Vec<char>,
+    symbols: Vec<(char, Segments)>,
+}
+
+impl FromStr for SegmentDisplay {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut table: Vec<(char, &str)> = Vec::new();
+        for l in s.lines().filter(|l| !l.is_empty() && !l.starts_with('#')) {
+            let (symbol, lit) = parse::split_once(s, l, " ")?;
+            let mut chars = symbol.chars();
+            let (Some(symbol), None) = (chars.next(), chars.next()) else {
+                return Err(ParseError::at(
+                    s,
+                    symbol,
+                    ParseErrorKind::Invalid("symbols are a single character".to_string()),
+                ));
+            };
+            table.push((symbol, lit));
+        }
+
+        let mut segments: Vec<char> = table.iter().flat_map(|(_, lit)| lit.chars()).collect();
+        segments.sort();
+        segments.dedup();
+        if segments.is_empty() {
+            return Err(ParseError::at(s, s, ParseErrorKind::Empty));
+        }
+        if segments.len() > Segments::BITS as usize {
+            return Err(ParseError::at(
+                s,
+                s,
+                ParseErrorKind::Invalid(format!("more than {} segments", Segments::BITS)),
+            ));
+        }
+
+        let mut display = SegmentDisplay {
+            segments,
+            symbols: Vec::new(),
+        };
+        for (symbol, lit) in table {
+            let lit_segments = display.wires(lit.chars()).unwrap_or(0);
+            if let Some((other, _)) = display.symbols.iter().find(|(_, l)| *l == lit_segments) {
+                return Err(ParseError::at(
+                    s,
+                    lit,
+                    ParseErrorKind::Invalid(format!("{} lit like {}", symbol, other)),
+                ));
+            }
+            display.symbols.push((symbol, lit_segments));
+        }
+        Ok(display)
+    }
+}
+
+impl SegmentDisplay {
+    pub fn seven_segment_digits() -> SegmentDisplay {
+        SEVEN_SEGMENT_DIGITS.parse().unwrap()
+    }
+
+    pub fn load(filename: &str) -> Result<SegmentDisplay, Box<dyn Error>> {
+        let content =
+            fs::read_to_string(filename).map_err(|e| format!("cannot read {}: {}", filename, e))?;
+        Ok(content.parse()?)
+    }
+
+    fn all(&self) -> Segments {
+        Segments::MAX >> (Segments::BITS as usize - self.segments.len())
+    }
+
+    /// `None` if a wire isn't named after a segment.
+    fn wires(&self, mut names: impl Iterator<Item = char>) -> Option<Segments> {
+        names.try_fold(0, |acc, c| {
+            Some(acc | 1 << self.segments.iter().position(|&s| s == c)?)
+        })
+    }
+
+    /// Row of `symbol` in the table.
+    fn value(&self, symbol: char) -> Option<usize> {
+        self.symbols.iter().position(|&(s, _)| s == symbol)
+    }
+
+    fn symbol(&self, lit: Segments) -> Option<char> {
+        self.symbols
+            .iter()
+            .find(|(_, l)| *l == lit)
+            .map(|&(s, _)| s)
+    }
+}
+
+/// Segment lit by each wire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wiring(Vec<usize>);
+
+#[derive(Debug)]
+pub enum WiringError {
+    /// More than one wiring lights a symbol for every signal.
+    Ambiguous,
+    Contradictory(String),
+}
+
+impl fmt::Display for WiringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WiringError::Ambiguous => write!(f, "ambiguous wiring"),
+            WiringError::Contradictory(reason) => write!(f, "contradictory wiring: {}", reason),
+        }
+    }
+}
+
+impl Error for WiringError {}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.iter().collect::<String>())
+    }
+}
+
+impl Signal {
+    fn wires(&self, display: &SegmentDisplay) -> Result<Segments, WiringError> {
+        display.wires(self.0.iter().copied()).ok_or_else(|| {
+            WiringError::Contradictory(format!("{} has wires the display doesn't have", self))
+        })
+    }
+
+    fn possible_symbols(&self, display: &SegmentDisplay) -> Vec<char> {
+        display
+            .symbols
+            .iter()
+            .filter(|(_, lit)| lit.count_ones() as usize == self.0.len())
+            .map(|&(s, _)| s)
+            .collect()
+    }
+
+    fn unique_symbol(&self, display: &SegmentDisplay) -> Option<char> {
+        let possible = self.possible_symbols(display);
+        match possible.len() {
+            1 => Some(possible[0]),
+            _ => None,
+        }
+    }
+
+    fn decode(&self, display: &SegmentDisplay, wiring: &Wiring) -> Option<char> {
+        let wires = display.wires(self.0.iter().copied())?;
+        let lit = (0..wiring.0.len())
+            .filter(|w| wires & 1 << w != 0)
+            .fold(0, |acc, w| acc | 1 << wiring.0[w]);
+        display.symbol(lit)
+    }
+}
+
+impl Entry {
+    fn signals(&self) -> impl Iterator<Item = &Signal> {
+        self.patterns.0.iter().chain(self.outputs.0.iter())
+    }
+
+    /// Narrows down the segments each wire can light: a signal can only be one of the
+    /// symbols with as many segments (which pins down 1, 4, 7 and 8 right away for the
+    /// digits of the puzzle), so its wires light segments of those symbols and the other
+    /// wires segments they don't light. The other symbols get ruled out as the candidates
+    /// shrink, what's left once nothing changes anymore is settled by trying the remaining
+    /// wirings.
+    pub fn wiring(&self, display: &SegmentDisplay) -> Result<Wiring, WiringError> {
+        let all = display.all();
+        let segment_count = display.segments.len();
+        let mut candidates = vec![all; segment_count];
+        loop {
+            let before = candidates.clone();
+            for signal in self.signals() {
+                let wires = signal.wires(display)?;
+                let possible: Vec<Segments> = display
+                    .symbols
+                    .iter()
+                    .map(|&(_, lit)| lit)
+                    .filter(|&lit| {
+                        lit.count_ones() == wires.count_ones()
+                            && (0..segment_count).all(|w| {
+                                let lit = if wires & 1 << w != 0 { lit } else { all & !lit };
+                                candidates[w] & lit != 0
+                            })
+                    })
+                    .collect();
+                if possible.is_empty() {
+                    return Err(WiringError::Contradictory(format!(
+                        "{} can't be any symbol",
+                        signal
+                    )));
+                }
+                let lit = possible.iter().fold(0, |acc, l| acc | l);
+                let unlit = possible.iter().fold(0, |acc, l| acc | (all & !l));
+                for (w, candidate) in candidates.iter_mut().enumerate() {
+                    *candidate &= if wires & 1 << w != 0 { lit } else { unlit };
+                }
+            }
+            // a segment settled for a wire can't be lit by another one
+            for w in 0..segment_count {
+                if candidates[w].count_ones() == 1 {
+                    let settled = candidates[w];
+                    for (other, candidate) in candidates.iter_mut().enumerate() {
+                        if other != w {
+                            *candidate &= !settled;
+                        }
+                    }
+                }
+            }
+            if let Some(w) = candidates.iter().position(|&c| c == 0) {
+                return Err(WiringError::Contradictory(format!(
+                    "wire {} can't light any segment",
+                    display.segments[w]
+                )));
+            }
+            if candidates == before {
+                break;
+            }
+        }
+
+        let mut found = self.search(display, &candidates)?;
+        match found.len() {
+            0 => Err(WiringError::Contradictory(
+                "no wiring lights a symbol for every signal".to_string(),
+            )),
+            1 => Ok(found.remove(0)),
+            _ => Err(WiringError::Ambiguous),
+        }
+    }
+
+    /// Collects the wirings allowed by `candidates` that decode every signal, stops at 2.
+    fn search(
+        &self,
+        display: &SegmentDisplay,
+        candidates: &[Segments],
+    ) -> Result<Vec<Wiring>, WiringError> {
+        let signals = self
+            .signals()
+            .map(|s| s.wires(display))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut wiring = vec![None; candidates.len()];
+        let mut found = Vec::new();
+        search(display, candidates, &signals, &mut wiring, 0, &mut found);
+        Ok(found)
+    }
+
+    /// Symbols shown by the outputs.
+    pub fn decode(&self, display: &SegmentDisplay) -> Result<String, WiringError> {
+        let wiring = self.wiring(display)?;
+        self.outputs
+            .0
+            .iter()
+            .map(|s| {
+                s.decode(display, &wiring)
+                    .ok_or_else(|| WiringError::Contradictory(format!("{} isn't a symbol", s)))
+            })
+            .collect()
+    }
+}
+
+/// Extends the partial `wiring`, where `used` are the segments already lit by a wire,
+/// branching on the wire with the fewest segments left and giving up on it as soon as
+/// a signal lights segments that no symbol with as many segments lights.
+fn search(
+    display: &SegmentDisplay,
+    candidates: &[Segments],
+    signals: &[Segments],
+    wiring: &mut [Option<usize>],
+    used: Segments,
+    found: &mut Vec<Wiring>,
+) {
+    if found.len() > 1 {
+        return;
+    }
+    let next = (0..wiring.len())
+        .filter(|&w| wiring[w].is_none())
+        .min_by_key(|&w| (candidates[w] & !used).count_ones());
+    let Some(w) = next else {
+        found.push(Wiring(wiring.iter().map(|s| s.unwrap()).collect()));
+        return;
+    };
+    for segment in 0..candidates.len() {
+        if (candidates[w] & !used) & 1 << segment == 0 {
+            continue;
+        }
+        wiring[w] = Some(segment);
+        if signals
+            .iter()
+            .all(|&wires| fits_a_symbol(display, wires, wiring))
+        {
+            search(
+                display,
+                candidates,
+                signals,
+                wiring,
+                used | 1 << segment,
+                found,
+            );
+        }
+        wiring[w] = None;
+    }
+}
+
+/// Whether the segments lit so far by `wires` are part of a symbol with as many segments
+/// as there are wires, which once every wire is known means they light that symbol.
+fn fits_a_symbol(display: &SegmentDisplay, wires: Segments, wiring: &[Option<usize>]) -> bool {
+    let lit = (0..wiring.len())
+        .filter(|w| wires & 1 << w != 0)
+        .filter_map(|w| wiring[w])
+        .fold(0, |acc, segment| acc | 1 << segment);
+    display
+        .symbols
+        .iter()
+        .any(|&(_, l)| l.count_ones() == wires.count_ones() && l & lit == lit)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Input = Lines<Entry>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(unique_outputs(
+            &input.0,
+            &SegmentDisplay::seven_segment_digits(),
+        ))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        output_sum(&input.0, &SegmentDisplay::seven_segment_digits())
+    }
+}
+
+/// Number of outputs showing the only symbol of `display` with as many segments.
+pub fn unique_outputs(entries: &[Entry], display: &SegmentDisplay) -> usize {
+    entries
+        .iter()
+        .flat_map(|e| e.outputs.0.iter().map(|s| s.unique_symbol(display)))
+        .filter(|n| n.is_some())
+        .count()
+}
+
+/// Sum of the numbers shown by the outputs of each entry, every symbol being worth its
+/// row in the table of `display` and the number of symbols being the base, so that
+/// the digits of the puzzle read as decimal numbers.
+pub fn output_sum(entries: &[Entry], display: &SegmentDisplay) -> Result<u64, Box<dyn Error>> {
+    let base = display.symbols.len() as u64;
+    let mut sum = 0u64;
+    for (i, entry) in entries.iter().enumerate() {
+        let symbols = entry
+            .decode(display)
+            .map_err(|e| format!("entry {}: {}", i + 1, e))?;
+        let value = symbols.chars().try_fold(0u64, |n, c| {
+            n.checked_mul(base)?.checked_add(display.value(c)? as u64)
+        });
+        sum = value
+            .and_then(|value| sum.checked_add(value))
+            .ok_or_else(|| format!("entry {}: sum doesn't fit in 64 bits", i + 1))?;
+    }
+    Ok(sum)
+}
@@ -0,0 +1,103 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::grid::{Grid, Position};
+use crate::image::{self, ColourMap, Image, RED};
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Heightmap(Grid<u32>);
+
+impl FromStr for Heightmap {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Heightmap(s.parse()?))
+    }
+}
+
+impl Heightmap {
+    fn positions_to_values(&self, pos: Vec<Position>) -> Vec<u32> {
+        pos.iter().map(|&p| self.0[p]).collect()
+    }
+
+    fn low_points_pos(&self) -> Vec<Position> {
+        self.0
+            .positions()
+            .filter(|&p| self.0.neighbours4(p).all(|n| self.0[n] > self.0[p]))
+            .collect()
+    }
+
+    fn risk_levels_sum(&self) -> u32 {
+        self.positions_to_values(self.low_points_pos())
+            .iter()
+            .map(|p| p + 1)
+            .sum()
+    }
+
+    /// Index of the basin of each position in the order of the low points,
+    /// `None` for the 9s which are in no basin.
+    pub fn basins(&self) -> Grid<Option<usize>> {
+        let mut basins = Grid::new(self.0.width(), self.0.height(), None);
+        for (i, low_point) in self.low_points_pos().into_iter().enumerate() {
+            let mut todo = vec![low_point];
+            while let Some(pos) = todo.pop() {
+                if basins[pos].is_some() {
+                    continue;
+                }
+                basins[pos] = Some(i);
+                todo.extend(
+                    self.0
+                        .neighbours4(pos)
+                        .filter(|&n| self.0[n] > self.0[pos] && self.0[n] != 9),
+                );
+            }
+        }
+        basins
+    }
+
+    /// Heights from dark to bright, tinted with a colour per basin, the low points in red.
+    pub fn image(&self) -> Image {
+        let mut image = Image::from_values_in(&self.0, 0, 9, ColourMap::Grey);
+        let basins = self.basins();
+        for pos in basins.positions() {
+            if let Some(basin) = basins[pos] {
+                image.highlight(pos, image::category(basin), 0.5);
+            }
+        }
+        for pos in self.low_points_pos() {
+            image.highlight(pos, RED, 1.0);
+        }
+        image
+    }
+
+    fn basin_sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.low_points_pos().len()];
+        for basin in self.basins().iter().flatten() {
+            sizes[*basin] += 1;
+        }
+        sizes
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Input = Heightmap;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn part1(heightmap: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(heightmap.risk_levels_sum())
+    }
+
+    fn part2(heightmap: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        let mut sizes = heightmap.basin_sizes();
+        if sizes.len() < 3 {
+            Err("fewer than 3 basins")?;
+        }
+        sizes.sort();
+        Ok(sizes[sizes.len() - 3..].iter().product())
+    }
+}
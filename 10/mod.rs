@@ -0,0 +1,401 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError, ParseErrorKind};
+use crate::solution::{Lines, Solution, Streaming};
+
+/// Delimiters of the puzzle and their corrupted and autocomplete scores.
+pub const PUZZLE_DELIMITERS: &str = "\
+( ) 3 1
+[ ] 57 2
+{ } 1197 3
+< > 25137 4
+";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelimiterPair {
+    pub open: String,
+    pub close: String,
+    /// Score of an unexpected `close`.
+    pub corrupted_score: u64,
+    /// Score of a missing `close`.
+    pub autocomplete_score: u64,
+}
+
+impl DelimiterPair {
+    /// Same token for both sides like quotes, it closes the pair if it's the last opened one
+    /// and any other token until then is ignored.
+    fn is_symmetric(&self) -> bool {
+        self.open == self.close
+    }
+}
+
+/// Delimiter pairs known by the checker, parsed from one
+/// `<open> <close> <corrupted score> <autocomplete score>` per line, for example
+/// `begin end 10 1`, lines starting with `#` are comments.
+///
+/// Tokens can be several characters long, the longest one wins when several match.
+/// Tokens starting or ending with a letter or digit only match whole words.
+#[derive(Debug, Clone)]
+pub struct DelimiterSet(Vec<DelimiterPair>);
+
+impl FromStr for DelimiterSet {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pairs: Vec<DelimiterPair> = Vec::new();
+        for l in s.lines().filter(|l| !l.is_empty() && !l.starts_with('#')) {
+            let (open, rest) = parse::split_once(s, l, " ")?;
+            let (close, rest) = parse::split_once(s, rest, " ")?;
+            let (corrupted_score, autocomplete_score) = parse::split_once(s, rest, " ")?;
+            for token in [open, close] {
+                if pairs.iter().any(|p| p.open == token || p.close == token) {
+                    return Err(ParseError::at(
+                        s,
+                        token,
+                        ParseErrorKind::Invalid(format!("{:?} used by another pair", token)),
+                    ));
+                }
+            }
+            pairs.push(DelimiterPair {
+                open: open.to_string(),
+                close: close.to_string(),
+                corrupted_score: parse::number(s, corrupted_score)?,
+                autocomplete_score: parse::number(s, autocomplete_score)?,
+            });
+        }
+        if pairs.is_empty() {
+            return Err(ParseError::at(s, s, ParseErrorKind::Empty));
+        }
+        Ok(DelimiterSet(pairs))
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl DelimiterSet {
+    pub fn puzzle() -> DelimiterSet {
+        PUZZLE_DELIMITERS.parse().unwrap()
+    }
+
+    pub fn load(filename: &str) -> Result<DelimiterSet, Box<dyn Error>> {
+        let content =
+            fs::read_to_string(filename).map_err(|e| format!("cannot read {}: {}", filename, e))?;
+        Ok(content.parse()?)
+    }
+
+    pub fn pairs(&self) -> &[DelimiterPair] {
+        &self.0
+    }
+
+    /// Pair whose closing token is `token`.
+    pub fn closing(&self, token: &str) -> Option<&DelimiterPair> {
+        self.0.iter().find(|p| p.close == token)
+    }
+
+    /// Longest token at `offset` in `text`, as the index of its pair and whether it opens it.
+    /// Symmetric pairs are reported as opening.
+    fn token_at(&self, text: &str, offset: usize) -> Option<(usize, bool, usize)> {
+        let rest = &text[offset..];
+        let before = text[..offset].chars().next_back();
+        self.0
+            .iter()
+            .enumerate()
+            .flat_map(|(i, p)| [(i, true, &p.open), (i, false, &p.close)])
+            .filter(|(_, _, token)| {
+                let Some(after) = rest.strip_prefix(token.as_str()) else {
+                    return false;
+                };
+                let after = after.chars().next();
+                let glued_before =
+                    token.starts_with(is_word_char) && before.is_some_and(is_word_char);
+                let glued_after = token.ends_with(is_word_char) && after.is_some_and(is_word_char);
+                !glued_before && !glued_after
+            })
+            .max_by_key(|&(_, open, token)| (token.len(), open))
+            .map(|(i, open, token)| (i, open, token.len()))
+    }
+
+    /// Closing tokens, with a space before the ones that are words.
+    fn closers<'a>(&'a self, pairs: impl Iterator<Item = usize> + 'a) -> String {
+        let mut closers = String::new();
+        for i in pairs {
+            let close = &self.0[i].close;
+            if close.starts_with(is_word_char) && closers.ends_with(is_word_char) {
+                closers.push(' ');
+            }
+            closers.push_str(close);
+        }
+        closers
+    }
+}
+
+/// Line of text where only the delimiters matter, anything else is ignored.
+pub struct Chunks(String);
+
+impl FromStr for Chunks {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Chunks(s.to_string()))
+    }
+}
+
+/// Closing token at byte `offset` which doesn't close the last opened pair,
+/// `expected` being `None` when nothing is open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub offset: usize,
+    pub found: String,
+    pub expected: Option<String>,
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.expected {
+            Some(expected) => write!(
+                f,
+                "offset {}: expected {:?}, found {:?}",
+                self.offset, expected, self.found
+            ),
+            None => write!(
+                f,
+                "offset {}: unexpected {:?}, nothing to close",
+                self.offset, self.found
+            ),
+        }
+    }
+}
+
+pub struct Check<'a> {
+    set: &'a DelimiterSet,
+    pub errors: Vec<SyntaxError>,
+    /// Pairs still open at the end and the byte offset of their opening token, innermost last.
+    unclosed: Vec<(usize, usize)>,
+}
+
+impl Chunks {
+    /// Goes on after an error as if the expected closer was there instead,
+    /// a closer with nothing open is skipped.
+    pub fn check<'a>(&self, set: &'a DelimiterSet) -> Check<'a> {
+        let text = &self.0;
+        let mut errors = Vec::new();
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        let mut offset = 0;
+        while let Some(c) = text[offset..].chars().next() {
+            let Some((pair, open, len)) = set.token_at(text, offset) else {
+                offset += c.len_utf8();
+                continue;
+            };
+            let closes_top = stack.last().is_some_and(|&(_, top)| top == pair);
+            let in_symmetric = stack
+                .last()
+                .is_some_and(|&(_, top)| set.0[top].is_symmetric());
+            if in_symmetric && !closes_top {
+                offset += len;
+                continue;
+            }
+            if open && !(set.0[pair].is_symmetric() && closes_top) {
+                stack.push((offset, pair));
+            } else {
+                let found = text[offset..offset + len].to_string();
+                match stack.pop() {
+                    Some(_) if closes_top => (),
+                    Some((_, top)) => errors.push(SyntaxError {
+                        offset,
+                        found,
+                        expected: Some(set.0[top].close.clone()),
+                    }),
+                    None => errors.push(SyntaxError {
+                        offset,
+                        found,
+                        expected: None,
+                    }),
+                }
+            }
+            offset += len;
+        }
+        Check {
+            set,
+            errors,
+            unclosed: stack,
+        }
+    }
+
+    /// Text with every wrong closer replaced by the expected one, closers with nothing
+    /// open removed and the completion appended, with spaces keeping words apart.
+    pub fn repair(&self, set: &DelimiterSet) -> String {
+        let check = self.check(set);
+        let mut repaired = String::with_capacity(self.0.len());
+        let mut last = 0;
+        for e in &check.errors {
+            repaired.push_str(&self.0[last..e.offset]);
+            last = e.offset + e.found.len();
+            if let Some(expected) = &e.expected {
+                push_token(&mut repaired, expected, self.0[last..].chars().next());
+            }
+        }
+        repaired.push_str(&self.0[last..]);
+        push_token(&mut repaired, &check.completion(), None);
+        repaired
+    }
+}
+
+/// Appends `token` to `text`, followed by `next`, with a space on the sides where it would
+/// make a longer word.
+fn push_token(text: &mut String, token: &str, next: Option<char>) {
+    if token.starts_with(is_word_char) && text.ends_with(is_word_char) {
+        text.push(' ');
+    }
+    text.push_str(token);
+    if token.ends_with(is_word_char) && next.is_some_and(is_word_char) {
+        text.push(' ');
+    }
+}
+
+impl Check<'_> {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.unclosed.is_empty()
+    }
+
+    pub fn unclosed_offsets(&self) -> impl Iterator<Item = usize> + '_ {
+        self.unclosed.iter().map(|&(offset, _)| offset)
+    }
+
+    /// Closers to append to close everything still open.
+    pub fn completion(&self) -> String {
+        self.set
+            .closers(self.unclosed.iter().rev().map(|&(_, pair)| pair))
+    }
+
+    /// Score of the first error, `None` if there is none.
+    pub fn corrupted_score(&self) -> Option<u64> {
+        let error = self.errors.first()?;
+        Some(self.set.closing(&error.found)?.corrupted_score)
+    }
+
+    /// Score of the completion, `None` if there are errors or nothing to complete.
+    /// Fails when the score doesn't fit in 64 bits, which takes about 28 unclosed pairs.
+    pub fn autocomplete_score(&self) -> Result<Option<u64>, Box<dyn Error>> {
+        if !self.errors.is_empty() || self.unclosed.is_empty() {
+            return Ok(None);
+        }
+        let score = self
+            .unclosed
+            .iter()
+            .rev()
+            .try_fold(0u64, |score, &(_, pair)| {
+                score
+                    .checked_mul(5)?
+                    .checked_add(self.set.0[pair].autocomplete_score)
+            })
+            .ok_or("autocomplete score doesn't fit in 64 bits")?;
+        Ok(Some(score))
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Lines<Chunks>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        corrupted_total(&input.0, &DelimiterSet::puzzle())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        middle_autocomplete_score(&input.0, &DelimiterSet::puzzle())
+    }
+}
+
+/// Sum of the scores of the first error of each line.
+pub fn corrupted_total(lines: &[Chunks], set: &DelimiterSet) -> Result<u64, Box<dyn Error>> {
+    let mut total = 0;
+    for chunks in lines {
+        let score = chunks.check(set).corrupted_score().unwrap_or(0);
+        total = add_corrupted_score(total, score)?;
+    }
+    Ok(total)
+}
+
+fn add_corrupted_score(total: u64, score: u64) -> Result<u64, Box<dyn Error>> {
+    Ok(total
+        .checked_add(score)
+        .ok_or("corrupted score total doesn't fit in 64 bits")?)
+}
+
+/// Middle autocomplete score of the lines without errors.
+pub fn middle_autocomplete_score(
+    lines: &[Chunks],
+    set: &DelimiterSet,
+) -> Result<u64, Box<dyn Error>> {
+    let mut autocomplete_scores = Vec::new();
+    for (i, chunks) in lines.iter().enumerate() {
+        let score = chunks
+            .check(set)
+            .autocomplete_score()
+            .map_err(|e| format!("line {}: {}", i + 1, e))?;
+        autocomplete_scores.extend(score);
+    }
+    middle_score(autocomplete_scores)
+}
+
+fn middle_score(mut autocomplete_scores: Vec<u64>) -> Result<u64, Box<dyn Error>> {
+    if autocomplete_scores.is_empty() {
+        Err("no incomplete line")?;
+    }
+    autocomplete_scores.sort();
+    Ok(autocomplete_scores[autocomplete_scores.len() / 2])
+}
+
+/// Errors of every line which isn't valid and the line once repaired, numbered from 1.
+pub fn repair_report(lines: &[Chunks], set: &DelimiterSet) -> String {
+    let mut report = String::new();
+    for (i, chunks) in lines.iter().enumerate() {
+        let check = chunks.check(set);
+        if check.is_valid() {
+            continue;
+        }
+        report.push_str(&format!("line {}:\n", i + 1));
+        for e in &check.errors {
+            report.push_str(&format!("  {}\n", e));
+        }
+        if !check.unclosed.is_empty() {
+            report.push_str(&format!(
+                "  unclosed, completed with {:?}\n",
+                check.completion()
+            ));
+        }
+        report.push_str(&format!("  repaired: {}\n", chunks.repair(set)));
+    }
+    if report.is_empty() {
+        report.push_str("every line is valid\n");
+    }
+    report
+}
+
+/// Only the autocomplete scores are kept, for the middle one.
+impl Streaming for Day10 {
+    fn solve_stream(reader: &mut dyn BufRead) -> Result<(u64, u64), Box<dyn Error>> {
+        let set = DelimiterSet::puzzle();
+        let mut corrupted_score = 0;
+        let mut autocomplete_scores = Vec::new();
+        for (i, chunks) in parse::read_lines::<Chunks>(reader).enumerate() {
+            let check = chunks?.check(&set);
+            corrupted_score =
+                add_corrupted_score(corrupted_score, check.corrupted_score().unwrap_or(0))?;
+            let score = check
+                .autocomplete_score()
+                .map_err(|e| format!("line {}: {}", i + 1, e))?;
+            autocomplete_scores.extend(score);
+        }
+        Ok((corrupted_score, middle_score(autocomplete_scores)?))
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::grid::{Grid, Position};
+use crate::image::{ColourMap, Image, CYAN};
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+#[derive(Clone, Debug)]
+pub struct Octopuses(Grid<u32>);
+
+impl FromStr for Octopuses {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Octopuses(s.parse()?))
+    }
+}
+
+impl Octopuses {
+    /// Advances one step, returns the number of flashes.
+    pub fn step(&mut self) -> usize {
+        let positions: Vec<Position> = self.0.positions().collect();
+        // Throw up for me please
+        let nines_pos: HashSet<Position> = positions
+            .iter()
+            .copied()
+            .filter(|&p| self.0[p] == 9)
+            .collect();
+        for energy in self.0.iter_mut() {
+            *energy += 1;
+        }
+        let mut flashes = 0;
+        let mut visited_flashed = HashSet::new();
+        while self.0.iter().any(|&x| x > 9) || visited_flashed.len() < nines_pos.len() {
+            for &p in &positions {
+                if (self.0[p] > 9 || nines_pos.contains(&p)) && !visited_flashed.contains(&p) {
+                    visited_flashed.insert(p);
+                    self.0[p] = 0;
+                    flashes += 1;
+                    let neighbours: Vec<Position> = self.0.neighbours8(p).collect();
+                    for n in neighbours {
+                        if !visited_flashed.contains(&n) {
+                            self.0[n] += 1;
+                        }
+                    }
+                }
+            }
+        }
+        flashes
+    }
+
+    /// Energy levels on the heat colours, the octopuses which flashed on the last step
+    /// (back to 0) in cyan.
+    pub fn image(&self) -> Image {
+        let mut image = Image::from_values_in(&self.0, 0, 9, ColourMap::Heat);
+        image.highlight_where(|p| self.0[p] == 0, CYAN, 1.0);
+        image
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Octopuses;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        let mut octopuses = input.clone();
+        let mut s = 0;
+        for _ in 0..100 {
+            s += octopuses.step();
+        }
+        Ok(s)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        let mut octopuses = input.clone();
+        let mut i = 1;
+        while octopuses.step() != octopuses.0.len() {
+            i += 1;
+        }
+        Ok(i)
+    }
+}
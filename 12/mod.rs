@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError, ParseErrorKind};
+use crate::solution::Solution;
+
+/// Caves are referred to by their index in `nodes`.
+pub struct CaveGraph {
+    nodes: Vec<String>,
+    adjacency: Vec<Vec<usize>>,
+    start: usize,
+    end: usize,
+}
+
+impl fmt::Debug for CaveGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, neighbours) in self.nodes.iter().zip(&self.adjacency) {
+            writeln!(
+                f,
+                "{:>5}: {}",
+                name,
+                (0..self.nodes.len())
+                    .map(|i| if neighbours.contains(&i) { "x" } else { "." })
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CaveGraph {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let edge_pairs: Vec<(&str, &str)> = s
+            .lines()
+            .map(|l| parse::split_once(s, l, "-"))
+            .collect::<Result<_, _>>()?;
+
+        let mut nodes: Vec<String> = Vec::new();
+        let mut adjacency: Vec<Vec<usize>> = Vec::new();
+        let mut id = |name: &str| match nodes.iter().position(|n| n == name) {
+            Some(i) => i,
+            None => {
+                nodes.push(name.to_string());
+                adjacency.push(Vec::new());
+                nodes.len() - 1
+            }
+        };
+        let edges: Vec<(usize, usize)> = edge_pairs
+            .iter()
+            .map(|(from, to)| (id(from), id(to)))
+            .collect();
+        for (from, to) in edges {
+            adjacency[from].push(to);
+            adjacency[to].push(from);
+        }
+
+        let find = |name: &str| {
+            nodes.iter().position(|n| n == name).ok_or_else(|| {
+                ParseError::at(
+                    s,
+                    &s[s.len()..],
+                    ParseErrorKind::Invalid(format!("no {} cave", name)),
+                )
+            })
+        };
+        let start = find("start")?;
+        let end = find("end")?;
+
+        Ok(CaveGraph {
+            nodes,
+            adjacency,
+            start,
+            end,
+        })
+    }
+}
+
+/// How many times a cave may be visited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    /// `None` for no limit.
+    pub visits: Option<u32>,
+    /// Extra visit tokens that can be spent on this cave once `visits` is reached.
+    pub extra: u32,
+}
+
+impl Budget {
+    pub const UNLIMITED: Budget = Budget {
+        visits: None,
+        extra: 0,
+    };
+
+    pub fn at_most(visits: u32) -> Budget {
+        Budget {
+            visits: Some(visits),
+            extra: 0,
+        }
+    }
+
+    pub fn with_extra(self, extra: u32) -> Budget {
+        Budget { extra, ..self }
+    }
+}
+
+/// Budget of each class of cave, `caves` overriding the others for the named caves.
+///
+/// Paths stop as soon as they reach `end`. For example, any two small caves twice:
+/// `VisitPolicy { extra_visits: 2, ..VisitPolicy::one_small_cave_twice() }`.
+#[derive(Debug, Clone)]
+pub struct VisitPolicy {
+    pub small: Budget,
+    pub large: Budget,
+    pub start: Budget,
+    pub end: Budget,
+    pub caves: Vec<(String, Budget)>,
+    /// Tokens shared by all the caves, each one allows a single extra visit.
+    pub extra_visits: u32,
+}
+
+impl VisitPolicy {
+    pub fn small_caves_once() -> VisitPolicy {
+        VisitPolicy {
+            small: Budget::at_most(1),
+            large: Budget::UNLIMITED,
+            start: Budget::at_most(1),
+            end: Budget::at_most(1),
+            caves: Vec::new(),
+            extra_visits: 0,
+        }
+    }
+
+    pub fn one_small_cave_twice() -> VisitPolicy {
+        VisitPolicy {
+            small: Budget::at_most(1).with_extra(1),
+            extra_visits: 1,
+            ..VisitPolicy::small_caves_once()
+        }
+    }
+}
+
+/// Visit counter of a limited cave, stored in `bits` bits from `shift` of the state.
+#[derive(Clone, Copy)]
+struct Counter {
+    shift: u32,
+    bits: u32,
+    visits: u64,
+    extra: u32,
+}
+
+/// A `VisitPolicy` applied to the caves of a graph, the visits of all the limited caves
+/// are packed in a single `u64` state.
+struct Rules {
+    counters: Vec<Option<Counter>>,
+    extra_visits: u32,
+}
+
+impl Rules {
+    fn new(graph: &CaveGraph, policy: &VisitPolicy) -> Result<Rules, Box<dyn Error>> {
+        for (name, _) in &policy.caves {
+            if !graph.nodes.contains(name) {
+                Err(format!("no cave {}", name))?;
+            }
+        }
+        let budgets: Vec<Budget> = (0..graph.nodes.len())
+            .map(|i| {
+                let name = &graph.nodes[i];
+                match policy.caves.iter().find(|(n, _)| n == name) {
+                    Some(&(_, budget)) => budget,
+                    None if i == graph.start => policy.start,
+                    None if i == graph.end => policy.end,
+                    None if CaveGraph::is_small_cave(name) => policy.small,
+                    None => policy.large,
+                }
+            })
+            .collect();
+
+        // going back and forth between two unlimited caves never ends
+        for (from, neighbours) in graph.adjacency.iter().enumerate() {
+            for &to in neighbours {
+                if from != graph.end
+                    && to != graph.end
+                    && budgets[from].visits.is_none()
+                    && budgets[to].visits.is_none()
+                {
+                    Err(format!(
+                        "infinitely many paths between {} and {}",
+                        graph.nodes[from], graph.nodes[to]
+                    ))?;
+                }
+            }
+        }
+
+        let mut shift = 0;
+        let mut counters = Vec::with_capacity(budgets.len());
+        for budget in budgets {
+            let Some(visits) = budget.visits else {
+                counters.push(None);
+                continue;
+            };
+            let extra = budget.extra.min(policy.extra_visits);
+            let max_visits = visits as u64 + extra as u64;
+            let bits = u64::BITS - max_visits.leading_zeros();
+            if shift + bits > u64::BITS {
+                Err("too many limited caves to track their visits")?;
+            }
+            counters.push(Some(Counter {
+                shift,
+                bits,
+                visits: visits as u64,
+                extra,
+            }));
+            shift += bits;
+        }
+        Ok(Rules {
+            counters,
+            extra_visits: policy.extra_visits,
+        })
+    }
+
+    /// State and extra visits left after entering `cave`, `None` if it can't be entered.
+    fn enter(&self, cave: usize, state: u64, extra_left: u32) -> Option<(u64, u32)> {
+        let Some(counter) = self.counters[cave] else {
+            return Some((state, extra_left));
+        };
+        let visits = (state >> counter.shift) & ((1 << counter.bits) - 1);
+        if visits < counter.visits {
+            Some((state + (1 << counter.shift), extra_left))
+        } else if extra_left > 0 && visits < counter.visits + counter.extra as u64 {
+            Some((state + (1 << counter.shift), extra_left - 1))
+        } else {
+            None
+        }
+    }
+}
+
+impl CaveGraph {
+    fn is_small_cave(name: &str) -> bool {
+        name == name.to_lowercase()
+    }
+
+    /// Number of paths from start to end allowed by `policy`.
+    pub fn count_paths(&self, policy: &VisitPolicy) -> Result<usize, Box<dyn Error>> {
+        let rules = Rules::new(self, policy)?;
+        let Some((state, extra_left)) = rules.enter(self.start, 0, rules.extra_visits) else {
+            return Ok(0);
+        };
+        let mut memo = HashMap::new();
+        Ok(self.count_from(&rules, self.start, state, extra_left, &mut memo))
+    }
+
+    fn count_from(
+        &self,
+        rules: &Rules,
+        cave: usize,
+        state: u64,
+        extra_left: u32,
+        memo: &mut HashMap<(usize, u64, u32), usize>,
+    ) -> usize {
+        if cave == self.end {
+            return 1;
+        }
+        if let Some(&count) = memo.get(&(cave, state, extra_left)) {
+            return count;
+        }
+        let count = self.adjacency[cave]
+            .iter()
+            .filter_map(|&next| {
+                let (state, extra_left) = rules.enter(next, state, extra_left)?;
+                Some(self.count_from(rules, next, state, extra_left, memo))
+            })
+            .sum();
+        memo.insert((cave, state, extra_left), count);
+        count
+    }
+
+    /// Every path counted by `count_paths`, built one at a time.
+    pub fn paths(&self, policy: &VisitPolicy) -> Result<Paths<'_>, Box<dyn Error>> {
+        let rules = Rules::new(self, policy)?;
+        let stack = match rules.enter(self.start, 0, rules.extra_visits) {
+            Some((state, extra_left)) => vec![PathStep {
+                cave: self.start,
+                next_neighbour: 0,
+                state,
+                extra_left,
+            }],
+            None => Vec::new(),
+        };
+        Ok(Paths {
+            graph: self,
+            rules,
+            stack,
+        })
+    }
+}
+
+struct PathStep {
+    cave: usize,
+    next_neighbour: usize,
+    state: u64,
+    extra_left: u32,
+}
+
+/// Depth first enumeration of the paths from start to end, as cave names.
+pub struct Paths<'a> {
+    graph: &'a CaveGraph,
+    rules: Rules,
+    stack: Vec<PathStep>,
+}
+
+impl<'a> Iterator for Paths<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = self.graph;
+        while let Some(step) = self.stack.last_mut() {
+            if step.cave == graph.end {
+                let path = self
+                    .stack
+                    .iter()
+                    .map(|s| graph.nodes[s.cave].as_str())
+                    .collect();
+                self.stack.pop();
+                return Some(path);
+            }
+            let Some(&next) = graph.adjacency[step.cave].get(step.next_neighbour) else {
+                self.stack.pop();
+                continue;
+            };
+            step.next_neighbour += 1;
+            if let Some((state, extra_left)) = self.rules.enter(next, step.state, step.extra_left) {
+                self.stack.push(PathStep {
+                    cave: next,
+                    next_neighbour: 0,
+                    state,
+                    extra_left,
+                });
+            }
+        }
+        None
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = CaveGraph;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part1(graph: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        graph.count_paths(&VisitPolicy::small_caves_once())
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        graph.count_paths(&VisitPolicy::one_small_cave_twice())
+    }
+}
+
+// examples:
+/*
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+*/
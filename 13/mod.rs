@@ -0,0 +1,167 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+use crate::parse::{self, ParseError, ParseErrorKind};
+use crate::solution::Solution;
+
+/// Sheets are refused above this many cells, the puzzle ones have about a million.
+pub const MAX_CELLS: usize = 1 << 26;
+
+fn check_size(width: usize, height: usize) -> Result<(), ParseErrorKind> {
+    if width.saturating_mul(height) > MAX_CELLS {
+        return Err(ParseErrorKind::Invalid(format!(
+            "{}x{} sheet is larger than {} cells",
+            width, height, MAX_CELLS
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+struct Sheet(Grid<bool>);
+
+impl fmt::Debug for Sheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.map(|&x| if x { '#' } else { '.' }))
+    }
+}
+
+impl FromStr for Sheet {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut dots: Vec<(usize, usize)> = Vec::new();
+        let (mut width, mut height) = (0, 0);
+        for l in s.lines() {
+            let (x, y) = parse::split_once(s, l, ",")?;
+            let dot: (usize, usize) = (parse::number(s, x)?, parse::number(s, y)?);
+            width = cmp::max(width, dot.0.saturating_add(1));
+            height = cmp::max(height, dot.1.saturating_add(1));
+            check_size(width, height).map_err(|kind| ParseError::at(s, l, kind))?;
+            dots.push(dot);
+        }
+        if dots.is_empty() {
+            return Err(ParseError::at(s, s, ParseErrorKind::Empty));
+        }
+        let mut sheet = Grid::new(width, height, false);
+        for d in dots {
+            sheet[(d.1, d.0)] = true;
+        }
+        Ok(Sheet(sheet))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct FoldInstruction {
+    axis: char,
+    index: usize,
+}
+
+impl FromStr for FoldInstruction {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let instruction = parse::strip_prefix(s, s, "fold along ")?;
+        let (axis, index) = parse::split_once(s, instruction, "=")?;
+        let axis = match axis {
+            "x" => 'x',
+            "y" => 'y',
+            _ => {
+                return Err(ParseError::at(
+                    s,
+                    axis,
+                    ParseErrorKind::Invalid(format!("axis {:?} is neither x nor y", axis)),
+                ))
+            }
+        };
+        Ok(FoldInstruction {
+            axis,
+            index: parse::number(s, index)?,
+        })
+    }
+}
+
+impl Sheet {
+    fn fold(&self, instruction: &FoldInstruction) -> Sheet {
+        let sheet = if instruction.axis == 'x' {
+            self.0.transpose()
+        } else {
+            self.0.clone()
+        };
+
+        let fold_dest = Grid::from_fn(sheet.width(), instruction.index, |(y, x)| {
+            let src_y = 2 * instruction.index - y;
+            let dot = |pos| sheet.get(pos).copied().unwrap_or(false);
+            dot((y, x)) || dot((src_y, x))
+        });
+        if instruction.axis == 'x' {
+            Sheet(fold_dest.transpose())
+        } else {
+            Sheet(fold_dest)
+        }
+    }
+
+    fn count_visible_dots(&self) -> usize {
+        self.0.iter().filter(|&&d| d).count()
+    }
+}
+
+pub struct Manual {
+    sheet: Sheet,
+    fold_instructions: Vec<FoldInstruction>,
+}
+
+impl FromStr for Manual {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sheet_str, fold_instructions_str) = parse::split_once(s, s, "\n\n")?;
+        let sheet: Sheet = sheet_str
+            .parse()
+            .map_err(|e: ParseError| e.within(s, sheet_str))?;
+        let fold_instructions: Vec<FoldInstruction> =
+            parse::lines(fold_instructions_str).map_err(|e| e.within(s, fold_instructions_str))?;
+        // a fold past the edge makes the sheet larger
+        let (mut width, mut height) = (sheet.0.width(), sheet.0.height());
+        for (l, instruction) in fold_instructions_str.lines().zip(&fold_instructions) {
+            if instruction.axis == 'x' {
+                width = instruction.index;
+            } else {
+                height = instruction.index;
+            }
+            check_size(width, height).map_err(|kind| ParseError::at(s, l, kind))?;
+        }
+        Ok(Manual {
+            sheet,
+            fold_instructions,
+        })
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn part1(manual: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        let first_instruction = manual
+            .fold_instructions
+            .first()
+            .ok_or("no fold instruction")?;
+        Ok(manual.sheet.fold(first_instruction).count_visible_dots())
+    }
+
+    fn part2(manual: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        let folded = manual
+            .fold_instructions
+            .iter()
+            .fold(manual.sheet.clone(), |sheet, instruction| {
+                sheet.fold(instruction)
+            });
+        Ok(format!("{:?}", folded))
+    }
+}
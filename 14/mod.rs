@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::number::Unsigned;
+use crate::parse::{self, ParseError, ParseErrorKind};
+use crate::solution::Solution;
+
+#[derive(Debug)]
+struct PolymerRule {
+    pair: (char, char),
+    inserted: char,
+}
+
+/// Elements of `s`, a slice of `input`, which are all uppercase letters.
+fn elements(input: &str, s: &str) -> Result<Vec<char>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| {
+            if c.is_ascii_uppercase() {
+                Ok(c)
+            } else {
+                Err(ParseError::at(
+                    input,
+                    &s[i..],
+                    ParseErrorKind::InvalidChar(c),
+                ))
+            }
+        })
+        .collect()
+}
+
+fn wrong_length(input: &str, s: &str, expected: &str) -> ParseError {
+    ParseError::at(
+        input,
+        s,
+        ParseErrorKind::Invalid(format!("expected {}, got {:?}", expected, s)),
+    )
+}
+
+impl FromStr for PolymerRule {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pair_str, inserted_str) = parse::split_once(s, s, " -> ")?;
+        let pair = match elements(s, pair_str)?[..] {
+            [first, second] => (first, second),
+            _ => return Err(wrong_length(s, pair_str, "a pair of elements")),
+        };
+        let inserted = match elements(s, inserted_str)?[..] {
+            [inserted] => inserted,
+            _ => return Err(wrong_length(s, inserted_str, "a single element")),
+        };
+        Ok(PolymerRule { pair, inserted })
+    }
+}
+
+#[derive(Debug)]
+struct PolymerPart1(Vec<char>);
+
+impl FromStr for PolymerPart1 {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let polymer = elements(s, s)?;
+        if polymer.len() < 2 {
+            return Err(wrong_length(s, s, "at least 2 elements"));
+        }
+        Ok(PolymerPart1(polymer))
+    }
+}
+
+/// Number of each pair of elements in the polymer, counted in `N`.
+#[derive(Debug, Clone)]
+struct PolymerPart2<N> {
+    counter: HashMap<(char, char), N>,
+    first_pair: (char, char),
+    last_pair: (char, char),
+}
+
+impl<N: Unsigned> FromStr for PolymerPart2<N> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if elements(s, s)?.len() < 2 {
+            return Err(wrong_length(s, s, "at least 2 elements"));
+        }
+        let mut counter = HashMap::new();
+        for c1 in 'A'..='Z' {
+            for c2 in 'A'..='Z' {
+                counter.insert((c1, c2), N::from(0));
+            }
+        }
+        let pairs = s.chars().zip(s.chars().skip(1));
+        for pair in pairs.clone() {
+            counter.insert(pair, counter[&pair].saturating_add(&N::from(1)));
+        }
+        Ok(PolymerPart2 {
+            counter,
+            first_pair: pairs.clone().next().unwrap(),
+            last_pair: pairs.clone().last().unwrap(),
+        })
+    }
+}
+
+impl PolymerPart1 {
+    fn step(&self, rules: &[PolymerRule]) -> PolymerPart1 {
+        let mut polymer: Vec<char> = Vec::new();
+        for (first, second) in self.0.iter().zip(self.0.iter().skip(1)) {
+            // println!("{} {}", first, second);
+            polymer.push(*first);
+            if let Some(rule) = rules.iter().find(|r| r.pair == (*first, *second)) {
+                polymer.push(rule.inserted);
+            }
+        }
+        polymer.push(self.0[self.0.len() - 1]);
+        PolymerPart1(polymer)
+    }
+}
+
+impl<N: Unsigned> PolymerPart2<N> {
+    fn step(&mut self, rules: &[PolymerRule]) -> Result<(), Box<dyn Error>> {
+        let mut new = self.clone();
+        for c1 in 'A'..='Z' {
+            for c2 in 'A'..='Z' {
+                new.counter.insert((c1, c2), N::from(0));
+            }
+        }
+        let add = |counter: &HashMap<(char, char), N>, pair, count: &N| {
+            counter[&pair].checked_add(count).ok_or_else(|| {
+                format!(
+                    "number of {}{} pairs doesn't fit in {}",
+                    pair.0,
+                    pair.1,
+                    std::any::type_name::<N>()
+                )
+            })
+        };
+        for (pair, count) in self.counter.iter() {
+            if *count == N::from(0) {
+                continue;
+            }
+            let Some(rule) = rules.iter().find(|r| r.pair == *pair) else {
+                // nothing inserted, the pair stays as it is
+                new.counter.insert(*pair, add(&new.counter, *pair, count)?);
+                continue;
+            };
+            let left = (pair.0, rule.inserted);
+            let right = (rule.inserted, pair.1);
+            if *pair == self.first_pair {
+                new.first_pair = left;
+            }
+            if *pair == self.last_pair {
+                new.last_pair = right;
+            }
+            new.counter.insert(left, add(&new.counter, left, count)?);
+            new.counter.insert(right, add(&new.counter, right, count)?);
+        }
+        *self = new;
+        Ok(())
+    }
+
+    /// Number of each element, the first of every pair and the last element.
+    fn element_counts(&self) -> Result<HashMap<char, N>, Box<dyn Error>> {
+        let mut counter: HashMap<char, N> = HashMap::new();
+        let pairs = self
+            .counter
+            .iter()
+            .filter(|(_, count)| **count != N::from(0));
+        for (&(c1, _), count) in pairs {
+            let total = counter.remove(&c1).unwrap_or(N::from(0));
+            let total = total.checked_add(count).ok_or_else(|| {
+                format!(
+                    "number of {} doesn't fit in {}",
+                    c1,
+                    std::any::type_name::<N>()
+                )
+            })?;
+            counter.insert(c1, total);
+        }
+        let last = counter.remove(&self.last_pair.1).unwrap_or(N::from(0));
+        counter.insert(self.last_pair.1, last.saturating_add(&N::from(1)));
+        Ok(counter)
+    }
+}
+
+pub struct Polymerization {
+    template: String,
+    rules: Vec<PolymerRule>,
+}
+
+impl FromStr for Polymerization {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (polymer_str, polymer_rules_str) = parse::split_once(s, s, "\n\n")?;
+        polymer_str
+            .parse::<PolymerPart1>()
+            .map_err(|e| e.within(s, polymer_str))?;
+        Ok(Polymerization {
+            template: polymer_str.to_string(),
+            rules: parse::lines(polymer_rules_str).map_err(|e| e.within(s, polymer_rules_str))?,
+        })
+    }
+}
+
+impl Polymerization {
+    /// Difference between the numbers of the most and least common elements
+    /// after `steps` steps, counted in `N`.
+    pub fn element_spread<N: Unsigned>(&self, steps: usize) -> Result<N, Box<dyn Error>> {
+        let mut polymer: PolymerPart2<N> = self.template.parse()?;
+        for _ in 0..steps {
+            polymer.step(&self.rules)?;
+        }
+        let counter = polymer.element_counts()?;
+        let most_common = counter.values().max().ok_or("no elements")?;
+        let least_common = counter.values().min().ok_or("no elements")?;
+        Ok(most_common.clone() - least_common.clone())
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Polymerization;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        let polymer: PolymerPart1 = input.template.parse()?;
+        let steps = 10;
+        let polymer = (0..steps).fold(polymer, |p, _| p.step(&input.rules));
+        let mut counter = HashMap::new();
+        for c in polymer.0 {
+            *counter.entry(c).or_insert(0) += 1;
+        }
+        let most_common = counter.values().max().unwrap();
+        let least_common = counter.values().min().unwrap();
+        Ok(most_common - least_common)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        input.element_spread(40)
+    }
+}
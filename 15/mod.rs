@@ -0,0 +1,142 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::grid::{Grid, Position};
+use crate::image::{ColourMap, Image, CYAN};
+use crate::parse::{ParseError, ParseErrorKind};
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct Cavern(Grid<u32>);
+
+impl FromStr for Cavern {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let risks: Grid<u32> = s.parse()?;
+        // risks go from 1 to 9, which is what wrapping them around when tiling relies on
+        if let Some(i) = s.find('0') {
+            return Err(ParseError::at(
+                s,
+                &s[i..],
+                ParseErrorKind::Invalid("risk levels go from 1 to 9".to_string()),
+            ));
+        }
+        Ok(Cavern(risks))
+    }
+}
+
+/// Positions visited from start to end (both included) and their total risk,
+/// the risk of the start position is not counted since we never enter it.
+#[derive(Debug)]
+pub struct Path {
+    pub cost: u32,
+    pub positions: Vec<Position>,
+}
+
+impl Cavern {
+    fn bottom_right(&self) -> Position {
+        (self.0.height() - 1, self.0.width() - 1)
+    }
+
+    /// Cavern repeated `times` times in both directions, each repetition to the right
+    /// or below adds 1 to the risk, wrapping back to 1 after 9.
+    fn tiled(&self, times: usize) -> Cavern {
+        Cavern(self.0.tiled(times, times, |&origin, (dist_i, dist_j)| {
+            (origin + dist_j as u32 + dist_i as u32 - 1) % 9 + 1
+        }))
+    }
+
+    /// Dijkstra from `start` to `end`, moving in all four directions.
+    fn shortest_path(&self, start: Position, end: Position) -> Option<Path> {
+        self.search(start, end, |_| 0)
+    }
+
+    /// A* from `start` to `end`, every risk being at least 1 the Manhattan distance
+    /// never overestimates the remaining cost.
+    fn shortest_path_astar(&self, start: Position, end: Position) -> Option<Path> {
+        self.search(start, end, |(y, x)| {
+            (y.abs_diff(end.0) + x.abs_diff(end.1)) as u32
+        })
+    }
+
+    /// Risks on the heat colours with the lowest risk path from the top left to the
+    /// bottom right in cyan, for the cavern repeated `times` times in both directions.
+    pub fn image(&self, times: usize) -> Result<Image, Box<dyn Error>> {
+        let cavern = self.tiled(times);
+        let path = cavern
+            .shortest_path_astar((0, 0), cavern.bottom_right())
+            .ok_or("no path to the bottom right")?;
+        let mut image = Image::from_values_in(&cavern.0, 1, 9, ColourMap::Heat);
+        for &pos in &path.positions {
+            image.highlight(pos, CYAN, 0.8);
+        }
+        Ok(image)
+    }
+
+    fn search<H>(&self, start: Position, end: Position, heuristic: H) -> Option<Path>
+    where
+        H: Fn(Position) -> u32,
+    {
+        let mut costs = Grid::new(self.0.width(), self.0.height(), u32::MAX);
+        let mut previous: Grid<Option<Position>> = Grid::new(self.0.width(), self.0.height(), None);
+        let mut queue = BinaryHeap::new();
+        costs[start] = 0;
+        queue.push(Reverse((heuristic(start), 0, start)));
+
+        while let Some(Reverse((_, cost, pos))) = queue.pop() {
+            if pos == end {
+                let mut positions = vec![end];
+                let mut current = end;
+                while let Some(p) = previous[current] {
+                    positions.push(p);
+                    current = p;
+                }
+                positions.reverse();
+                return Some(Path { cost, positions });
+            }
+            if cost > costs[pos] {
+                continue;
+            }
+            for next in self.0.neighbours4(pos) {
+                let next_cost = cost + self.0[next];
+                if next_cost < costs[next] {
+                    costs[next] = next_cost;
+                    previous[next] = Some(pos);
+                    queue.push(Reverse((next_cost + heuristic(next), next_cost, next)));
+                }
+            }
+        }
+        None
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Cavern;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn part1(cavern: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        let path = cavern
+            .shortest_path((0, 0), cavern.bottom_right())
+            .ok_or("no path to the bottom right")?;
+        Ok(path.cost)
+    }
+
+    fn part2(cavern: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        let big_cavern = cavern.tiled(5);
+        let path = big_cavern
+            .shortest_path_astar((0, 0), big_cavern.bottom_right())
+            .ok_or("no path to the bottom right")?;
+        debug_assert_eq!(
+            path.cost,
+            path.positions[1..].iter().map(|&p| big_cavern.0[p]).sum()
+        );
+        Ok(path.cost)
+    }
+}
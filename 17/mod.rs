@@ -0,0 +1,147 @@
+use std::cmp;
+use std::cmp::Ordering::*;
+use std::error::Error;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError, ParseErrorKind};
+use crate::solution::Solution;
+
+/// Bounds are refused beyond this distance from 0, every velocity up to it being tried.
+pub const MAX_COORDINATE: i64 = 1_000_000;
+
+fn coordinate(input: &str, s: &str) -> Result<i64, ParseError> {
+    let n: i64 = parse::number(input, s)?;
+    if n.unsigned_abs() > MAX_COORDINATE as u64 {
+        return Err(ParseError::at(
+            input,
+            s,
+            ParseErrorKind::Invalid(format!(
+                "coordinates go from -{} to {}",
+                MAX_COORDINATE, MAX_COORDINATE
+            )),
+        ));
+    }
+    Ok(n)
+}
+
+/// The puzzle ranges (`x=20..30`) include both of their bounds.
+pub struct TargetArea {
+    x_range: RangeInclusive<i64>,
+    y_range: RangeInclusive<i64>,
+}
+
+impl FromStr for TargetArea {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let area = parse::strip_prefix(s, s.trim(), "target area: ")?;
+        let (x_range_str, y_range_str) = parse::split_once(s, area, ", ")?;
+        let x_range_str = parse::strip_prefix(s, x_range_str, "x=")?;
+        let y_range_str = parse::strip_prefix(s, y_range_str, "y=")?;
+        let (x_start, x_end) = parse::split_once(s, x_range_str, "..")?;
+        let (y_start, y_end) = parse::split_once(s, y_range_str, "..")?;
+        Ok(TargetArea {
+            x_range: coordinate(s, x_start)?..=coordinate(s, x_end)?,
+            y_range: coordinate(s, y_start)?..=coordinate(s, y_end)?,
+        })
+    }
+}
+
+impl TargetArea {
+    fn contains(&self, probe: &Probe) -> bool {
+        self.x_range.contains(&probe.x) && self.y_range.contains(&probe.y)
+    }
+
+    /// True once the probe can never come back to the area: it's beyond it horizontally
+    /// and not going back, or below it and falling.
+    fn missed(&self, probe: &Probe) -> bool {
+        let passed_x = (probe.x > *self.x_range.end() && probe.x_velocity >= 0)
+            || (probe.x < *self.x_range.start() && probe.x_velocity <= 0);
+        let passed_y = probe.y < *self.y_range.start() && probe.y_velocity < 0;
+        passed_x || passed_y
+    }
+
+    /// Highest `y` reached by each initial velocity that lands in the area.
+    ///
+    /// A faster horizontal velocity than the farthest edge overshoots on the first step,
+    /// and a probe launched upward at `v` comes back to `y = 0` going down at `v + 1`,
+    /// so vertical velocities farther than the farthest edge from 0 always miss as well.
+    fn hits(&self) -> Vec<i64> {
+        let x_min = cmp::min(0, *self.x_range.start());
+        let x_max = cmp::max(0, *self.x_range.end());
+        let y_bound = cmp::max(self.y_range.start().abs(), self.y_range.end().abs());
+        let mut hits = Vec::new();
+        for x_velocity in x_min..=x_max {
+            for y_velocity in -y_bound..=y_bound {
+                if let Some(y_max) = self.launch(Probe::new(x_velocity, y_velocity)) {
+                    hits.push(y_max);
+                }
+            }
+        }
+        hits
+    }
+
+    fn launch(&self, mut probe: Probe) -> Option<i64> {
+        while !self.missed(&probe) {
+            if self.contains(&probe) {
+                return Some(probe.y_max);
+            }
+            probe.step();
+        }
+        None
+    }
+}
+
+struct Probe {
+    x: i64,
+    y: i64,
+    x_velocity: i64,
+    y_velocity: i64,
+    y_max: i64,
+}
+
+impl Probe {
+    fn new(x_velocity: i64, y_velocity: i64) -> Probe {
+        Probe {
+            x: 0,
+            y: 0,
+            y_max: 0,
+            x_velocity,
+            y_velocity,
+        }
+    }
+
+    fn step(&mut self) {
+        self.x += self.x_velocity;
+        self.y += self.y_velocity;
+        self.x_velocity += match self.x_velocity.cmp(&0) {
+            Less => 1,
+            Greater => -1,
+            Equal => 0,
+        };
+        self.y_velocity -= 1;
+        self.y_max = cmp::max(self.y_max, self.y);
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = TargetArea;
+    type Part1 = i64;
+    type Part2 = usize;
+
+    fn part1(target_area: &Self::Input) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(target_area
+            .hits()
+            .into_iter()
+            .max()
+            .ok_or("no velocity reaches the target area")?)
+    }
+
+    fn part2(target_area: &Self::Input) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(target_area.hits().len())
+    }
+}
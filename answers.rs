@@ -0,0 +1,79 @@
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::str::FromStr;
+
+/// Expected answer of one part of a day for a given input file.
+///
+/// Stored one per line in `NN/answers.txt` as `<input file> <part> <answer>`,
+/// where `\n` in the answer stands for a line break (e.g. day 13 letters).
+/// Options of `aoc run` the answer is for can come between the input file and the part,
+/// as `--option` or `--option=value`.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug)]
+pub struct Answer {
+    pub input: String,
+    pub options: Vec<String>,
+    pub part: u32,
+    pub expected: String,
+}
+
+impl FromStr for Answer {
+    type Err = Box<dyn Error>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (input, mut rest) = s.split_once(' ').ok_or("no part")?;
+        let mut options = Vec::new();
+        while rest.starts_with("--") {
+            let (option, after) = rest.split_once(' ').ok_or("no part")?;
+            options.push(option.to_string());
+            rest = after;
+        }
+        let (part, expected) = rest.split_once(' ').ok_or("no answer")?;
+        Ok(Answer {
+            input: input.to_string(),
+            options,
+            part: part.parse()?,
+            expected: expected.replace("\\n", "\n"),
+        })
+    }
+}
+
+impl Answer {
+    /// The options as arguments of `aoc run`.
+    pub fn run_args(&self) -> Vec<String> {
+        self.options
+            .iter()
+            .flat_map(|o| match o.split_once('=') {
+                Some((option, value)) => vec![option.to_string(), value.to_string()],
+                None => vec![o.clone()],
+            })
+            .collect()
+    }
+
+    pub fn matches(&self, result: &str) -> bool {
+        self.expected.trim_end() == result.trim_end()
+    }
+}
+
+pub fn answers_filename(day: u32) -> String {
+    format!("{:02}/answers.txt", day)
+}
+
+/// Answers recorded for `day`, none if the day has no answers file.
+pub fn load(day: u32) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let filename = answers_filename(day);
+    let content = match fs::read_to_string(&filename) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => Err(format!("cannot read {}: {}", filename, e))?,
+    };
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|(i, l)| {
+            l.parse()
+                .map_err(|e| format!("{}:{}: {}", filename, i + 1, e).into())
+        })
+        .collect()
+}
@@ -0,0 +1,208 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Step {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::Part1 => "part1",
+            Step::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Step> {
+        match name {
+            "parse" => Some(Step::Parse),
+            "part1" => Some(Step::Part1),
+            "part2" => Some(Step::Part2),
+            _ => None,
+        }
+    }
+}
+
+/// Summary of the durations of several runs of one step of a day.
+#[derive(Debug, Clone)]
+pub struct Measure {
+    pub day: u32,
+    pub step: Step,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Measure {
+    fn new(day: u32, step: Step, mut durations: Vec<Duration>) -> Measure {
+        durations.sort();
+        let runs = durations.len();
+        Measure {
+            day,
+            step,
+            runs,
+            min: durations[0],
+            median: durations[runs / 2],
+            mean: durations.iter().sum::<Duration>() / runs as u32,
+        }
+    }
+}
+
+impl fmt::Display for Measure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02} {:<5}  min {:>10}  median {:>10}  mean {:>10}",
+            self.day,
+            self.step.name(),
+            format!("{:.2?}", self.min),
+            format!("{:.2?}", self.median),
+            format!("{:.2?}", self.mean),
+        )
+    }
+}
+
+fn time<T, F>(runs: usize, mut f: F) -> Result<Vec<Duration>, Box<dyn Error>>
+where
+    F: FnMut() -> Result<T, Box<dyn Error>>,
+{
+    (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f()?);
+            Ok(start.elapsed())
+        })
+        .collect()
+}
+
+/// Times `runs` parses of `s` and `runs` runs of each part on the parsed input.
+pub fn measure<S: Solution>(s: &str, runs: usize) -> Result<Vec<Measure>, Box<dyn Error>> {
+    let parse = time(runs, || Ok(S::parse(black_box(s))?))?;
+    let input = S::parse(s)?;
+    let part1 = time(runs, || S::part1(black_box(&input)))?;
+    let part2 = time(runs, || S::part2(black_box(&input)))?;
+    Ok(vec![
+        Measure::new(S::DAY, Step::Parse, parse),
+        Measure::new(S::DAY, Step::Part1, part1),
+        Measure::new(S::DAY, Step::Part2, part2),
+    ])
+}
+
+const CSV_HEADER: &str = "day,step,runs,min_ns,median_ns,mean_ns";
+
+pub fn to_csv(measures: &[Measure]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for m in measures {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            m.day,
+            m.step.name(),
+            m.runs,
+            m.min.as_nanos(),
+            m.median.as_nanos(),
+            m.mean.as_nanos()
+        ));
+    }
+    csv
+}
+
+pub fn to_json(measures: &[Measure]) -> String {
+    let records: Vec<String> = measures
+        .iter()
+        .map(|m| {
+            format!(
+                "  {{\"day\": {}, \"step\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}}}",
+                m.day,
+                m.step.name(),
+                m.runs,
+                m.min.as_nanos(),
+                m.median.as_nanos(),
+                m.mean.as_nanos()
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+/// Writes `measures` as JSON if `filename` ends with `.json`, CSV otherwise.
+pub fn save(filename: &str, measures: &[Measure]) -> Result<(), Box<dyn Error>> {
+    let content = if filename.ends_with(".json") {
+        to_json(measures)
+    } else {
+        to_csv(measures)
+    };
+    fs::write(filename, content).map_err(|e| format!("cannot write {}: {}", filename, e))?;
+    Ok(())
+}
+
+/// Reads measures previously written by `save` as CSV.
+pub fn load_csv(filename: &str) -> Result<Vec<Measure>, Box<dyn Error>> {
+    let content =
+        fs::read_to_string(filename).map_err(|e| format!("cannot read {}: {}", filename, e))?;
+    content
+        .lines()
+        .enumerate()
+        .skip(1)
+        .map(|(i, l)| {
+            let error = || format!("{}:{}: bad measure {:?}", filename, i + 1, l);
+            let fields: Vec<&str> = l.split(',').collect();
+            let [day, step, runs, min, median, mean] = fields[..] else {
+                return Err(error().into());
+            };
+            let nanos = |s: &str| s.parse().map(Duration::from_nanos).map_err(|_| error());
+            Ok(Measure {
+                day: day.parse().map_err(|_| error())?,
+                step: Step::from_name(step).ok_or_else(error)?,
+                runs: runs.parse().map_err(|_| error())?,
+                min: nanos(min)?,
+                median: nanos(median)?,
+                mean: nanos(mean)?,
+            })
+        })
+        .collect()
+}
+
+/// Measure whose median got slower than its baseline by more than `threshold` percent.
+pub struct Regression<'a> {
+    pub measure: &'a Measure,
+    pub baseline: &'a Measure,
+    pub percent: f64,
+}
+
+pub fn regressions<'a>(
+    measures: &'a [Measure],
+    baseline: &'a [Measure],
+    threshold: f64,
+) -> Vec<Regression<'a>> {
+    measures
+        .iter()
+        .filter_map(|m| {
+            let b = baseline
+                .iter()
+                .find(|b| b.day == m.day && b.step == m.step)?;
+            let percent = (m.median.as_secs_f64() / b.median.as_secs_f64().max(f64::MIN_POSITIVE)
+                - 1.0)
+                * 100.0;
+            if percent > threshold {
+                Some(Regression {
+                    measure: m,
+                    baseline: b,
+                    percent,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
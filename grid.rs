@@ -0,0 +1,292 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::parse::{ParseError, ParseErrorKind};
+
+/// `(y, x)` coordinates, `y` being the row and `x` the column.
+pub type Position = (usize, usize);
+
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular 2D grid stored row after row in a single `Vec`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(Position) -> T,
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f((y, x)));
+            }
+        }
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Box<dyn Error>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        if let Some(y) = rows.iter().position(|r| r.len() != width) {
+            Err(format!(
+                "row {} has {} cells, expected {}",
+                y,
+                rows[y].len(),
+                width
+            ))?;
+        }
+        Ok(Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (y, x): Position) -> bool {
+        y < self.height && x < self.width
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self[pos])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self[pos])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (y, x)))
+    }
+
+    /// Every cell, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    fn offset_positions<'a>(
+        &'a self,
+        (y, x): Position,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter().filter_map(move |&(dy, dx)| {
+            let pos = (y.checked_add_signed(dy)?, x.checked_add_signed(dx)?);
+            if self.contains(pos) {
+                Some(pos)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Positions above, left, below and right of `pos` that are in the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(pos, &OFFSETS4)
+    }
+
+    /// Positions around `pos`, diagonals included, that are in the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(pos, &OFFSETS8)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(y, x)| self[(x, y)].clone())
+    }
+
+    /// Grid repeated `times_y` times downward and `times_x` times rightward,
+    /// `f` gets the original cell and the `(y, x)` index of the tile it's copied to.
+    pub fn tiled<F>(&self, times_y: usize, times_x: usize, f: F) -> Grid<T>
+    where
+        F: Fn(&T, Position) -> T,
+    {
+        Grid::from_fn(self.width * times_x, self.height * times_y, |(y, x)| {
+            let origin = &self[(y % self.height, x % self.width)];
+            f(origin, (y / self.height, x / self.width))
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+    fn index(&self, (y, x): Position) -> &T {
+        assert!(
+            x < self.width,
+            "x {} out of grid of width {}",
+            x,
+            self.width
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (y, x): Position) -> &mut T {
+        assert!(
+            x < self.width,
+            "x {} out of grid of width {}",
+            x,
+            self.width
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Grid of single digits such as `2199943210`.
+impl FromStr for Grid<u32> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for l in s.lines() {
+            for (i, c) in l.char_indices() {
+                let digit = c
+                    .to_digit(10)
+                    .ok_or_else(|| ParseError::at(s, &l[i..], ParseErrorKind::InvalidChar(c)))?;
+                cells.push(digit);
+            }
+            let row_width = l.chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(
+                        s,
+                        l,
+                        ParseErrorKind::Invalid(format!(
+                            "row of {} cells, expected {}",
+                            row_width, width
+                        )),
+                    ))
+                }
+                _ => (),
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                cells,
+                width,
+                height,
+            }),
+            _ => Err(ParseError::at(s, s, ParseErrorKind::Empty)),
+        }
+    }
+}
+
+/// Cells of a row next to each other, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            write!(f, "{}:", y)?;
+            for cell in row {
+                write!(f, " {:?}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use crate::grid::{Grid, Position};
+
+pub type Rgb = [u8; 3];
+
+pub const RED: Rgb = [230, 40, 40];
+pub const CYAN: Rgb = [40, 220, 230];
+
+/// Images are refused above this many pixels, 64M pixels being already 192 MB of PPM.
+pub const MAX_PIXELS: usize = 1 << 26;
+
+/// Colours of numbers from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColourMap {
+    /// From black to white.
+    Grey,
+    /// From black to red, yellow and white.
+    Heat,
+}
+
+impl ColourMap {
+    /// Colour of `t`, clamped between 0 and 1.
+    pub fn colour(&self, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            ColourMap::Grey => [(t * 255.0).round() as u8; 3],
+            ColourMap::Heat => {
+                // each third of the range fills one channel
+                let channel = |start: f64| (((t - start) * 3.0).clamp(0.0, 1.0) * 255.0) as u8;
+                [channel(0.0), channel(1.0 / 3.0), channel(2.0 / 3.0)]
+            }
+        }
+    }
+}
+
+/// Colour of the `i`th category, consecutive ones a golden angle apart on the hue circle
+/// so that any number of them stay apart.
+pub fn category(i: usize) -> Rgb {
+    let hue = (i as f64 * 137.508) % 360.0 / 60.0;
+    let x = 1.0 - (hue % 2.0 - 1.0).abs();
+    let (r, g, b) = match hue as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    [r, g, b].map(|c: f64| (55.0 + c * 200.0) as u8)
+}
+
+pub fn check_size(width: usize, height: usize) -> Result<(), Box<dyn Error>> {
+    if width.saturating_mul(height) > MAX_PIXELS {
+        Err(format!(
+            "{}x{} image is larger than {} pixels",
+            width, height, MAX_PIXELS
+        ))?;
+    }
+    Ok(())
+}
+
+/// RGB image, pixel `(y, x)` being on row `y` from the top and column `x` from the left.
+#[derive(Clone)]
+pub struct Image(Grid<Rgb>);
+
+impl Image {
+    /// One pixel per value, coloured by `map` from the smallest value to the largest one.
+    pub fn from_values(values: &Grid<u32>, map: ColourMap) -> Image {
+        let min = values.iter().copied().min().unwrap_or(0);
+        let max = values.iter().copied().max().unwrap_or(0);
+        Image::from_values_in(values, min, max, map)
+    }
+
+    /// Like `from_values`, with `min` and `max` as the ends of `map`.
+    pub fn from_values_in(values: &Grid<u32>, min: u32, max: u32, map: ColourMap) -> Image {
+        let range = max.saturating_sub(min).max(1) as f64;
+        Image(values.map(|&v| map.colour(v.saturating_sub(min) as f64 / range)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.0.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.0.height()
+    }
+
+    /// Mixes `colour` into the pixel at `pos`, replacing it when `opacity` is 1.
+    pub fn highlight(&mut self, pos: Position, colour: Rgb, opacity: f64) {
+        let opacity = opacity.clamp(0.0, 1.0);
+        let pixel = &mut self.0[pos];
+        for (p, c) in pixel.iter_mut().zip(colour) {
+            *p = (*p as f64 * (1.0 - opacity) + c as f64 * opacity).round() as u8;
+        }
+    }
+
+    pub fn highlight_where<F>(&mut self, mut f: F, colour: Rgb, opacity: f64)
+    where
+        F: FnMut(Position) -> bool,
+    {
+        let positions: Vec<Position> = self.0.positions().filter(|&p| f(p)).collect();
+        for pos in positions {
+            self.highlight(pos, colour, opacity);
+        }
+    }
+
+    /// Every pixel as a square of `factor` by `factor` pixels.
+    pub fn scaled(&self, factor: usize) -> Image {
+        let factor = factor.max(1);
+        Image(Grid::from_fn(
+            self.width() * factor,
+            self.height() * factor,
+            |(y, x)| self.0[(y / factor, x / factor)],
+        ))
+    }
+
+    /// Binary PPM (P6).
+    pub fn write_ppm(&self, out: &mut dyn Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        for pixel in self.0.iter() {
+            out.write_all(pixel)?;
+        }
+        Ok(())
+    }
+
+    /// PNG with uncompressed deflate blocks, the format being all that's needed
+    /// from it to be readable anywhere.
+    pub fn write_png(&self, out: &mut dyn Write) -> io::Result<()> {
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+        let mut header = Vec::new();
+        header.extend((self.width() as u32).to_be_bytes());
+        header.extend((self.height() as u32).to_be_bytes());
+        // 8 bits per channel, RGB, default compression, filtering and no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+        write_chunk(out, b"IHDR", &header)?;
+
+        // each row starts with its filter, 0 for none
+        let mut raw = Vec::with_capacity(self.height() * (self.width() * 3 + 1));
+        for row in self.0.rows() {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+        let mut zlib = vec![0x78, 0x01];
+        let blocks: Vec<&[u8]> = raw.chunks(u16::MAX as usize).collect();
+        for (i, block) in blocks.iter().enumerate() {
+            zlib.push((i + 1 == blocks.len()) as u8);
+            zlib.extend((block.len() as u16).to_le_bytes());
+            zlib.extend((!(block.len() as u16)).to_le_bytes());
+            zlib.extend_from_slice(block);
+        }
+        zlib.extend(adler32(&raw).to_be_bytes());
+        write_chunk(out, b"IDAT", &zlib)?;
+        write_chunk(out, b"IEND", &[])
+    }
+
+    /// PNG if `filename` ends in `.png`, PPM if it ends in `.ppm`.
+    pub fn save(&self, filename: &str) -> Result<(), Box<dyn Error>> {
+        let write = if filename.ends_with(".png") {
+            Image::write_png
+        } else if filename.ends_with(".ppm") {
+            Image::write_ppm
+        } else {
+            Err(format!("{} is neither a .ppm nor a .png file", filename))?
+        };
+        let file =
+            File::create(filename).map_err(|e| format!("cannot write {}: {}", filename, e))?;
+        let mut out = BufWriter::new(file);
+        write(self, &mut out)
+            .and_then(|_| out.flush())
+            .map_err(|e| format!("cannot write {}: {}", filename, e))?;
+        Ok(())
+    }
+}
+
+fn write_chunk(out: &mut dyn Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+fn crc32<'a>(bytes: impl Iterator<Item = &'a u8>) -> u32 {
+    let mut crc = u32::MAX;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,40 @@
+pub mod answers;
+pub mod bench;
+pub mod grid;
+pub mod image;
+pub mod number;
+pub mod parse;
+pub mod solution;
+
+#[path = "01/mod.rs"]
+pub mod day01;
+#[path = "02/mod.rs"]
+pub mod day02;
+#[path = "03/mod.rs"]
+pub mod day03;
+#[path = "04/mod.rs"]
+pub mod day04;
+#[path = "05/mod.rs"]
+pub mod day05;
+#[path = "06/mod.rs"]
+pub mod day06;
+#[path = "07/mod.rs"]
+pub mod day07;
+#[path = "08/mod.rs"]
+pub mod day08;
+#[path = "09/mod.rs"]
+pub mod day09;
+#[path = "10/mod.rs"]
+pub mod day10;
+#[path = "11/mod.rs"]
+pub mod day11;
+#[path = "12/mod.rs"]
+pub mod day12;
+#[path = "13/mod.rs"]
+pub mod day13;
+#[path = "14/mod.rs"]
+pub mod day14;
+#[path = "15/mod.rs"]
+pub mod day15;
+#[path = "17/mod.rs"]
+pub mod day17;
@@ -0,0 +1,960 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::process;
+use std::str::FromStr;
+
+use aoc::number::{BigUint, Unsigned};
+use aoc::solution::{Solution, Streaming};
+use aoc::{
+    answers, bench, day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11,
+    day12, day13, day14, day15, day17, image,
+};
+
+type Solver = fn(&str, &[u32]) -> Result<Vec<String>, Box<dyn Error>>;
+type StreamSolver = fn(&mut dyn BufRead, &[u32]) -> Result<Vec<String>, Box<dyn Error>>;
+type Bencher = fn(&str, usize) -> Result<Vec<bench::Measure>, Box<dyn Error>>;
+
+#[derive(Clone, Copy)]
+struct Day {
+    number: u32,
+    solve: Solver,
+    stream: Option<StreamSolver>,
+    bench: Bencher,
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        day!($solution, None)
+    };
+    ($solution:ty, streaming) => {
+        day!($solution, Some(<$solution>::solve_reader))
+    };
+    ($solution:ty, $stream:expr) => {
+        Day {
+            number: <$solution>::DAY,
+            solve: <$solution>::solve,
+            stream: $stream,
+            bench: bench::measure::<$solution>,
+        }
+    };
+}
+
+const DAYS: &[Day] = &[
+    day!(day01::Day01, streaming),
+    day!(day02::Day02, streaming),
+    day!(day03::Day03),
+    day!(day04::Day04),
+    day!(day05::Day05),
+    day!(day06::Day06),
+    day!(day07::Day07),
+    day!(day08::Day08),
+    day!(day09::Day09),
+    day!(day10::Day10, streaming),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day14::Day14),
+    day!(day15::Day15),
+    day!(day17::Day17),
+];
+
+const USAGE: &str = "usage: aoc run [--day N] [--part 1|2] [--input PATH|-] [--stream]
+       aoc run --day 1 --window K [--input PATH|-]
+       aoc run --day 2 --part 1|2 --trace [--input PATH|-]
+       aoc run --day 4 --analyse|--what-if N,N,... [--diagonals] [--input PATH|-]
+       aoc run --day 5 [--part 1|2] --at X,Y|--region X1,Y1,X2,Y2 [--input PATH|-]
+       aoc run --day 5 [--part 1|2] --analytic [--at X,Y] [--input PATH|-]
+       aoc run --day 6 --days N|--exceeds X [--lifecycle RESET,NEWBORN] [--big] [--input PATH|-]
+       aoc run --day 8 --display PATH [--part 1|2] [--input PATH|-]
+       aoc run --day 10 [--delimiters PATH] [--part 1|2|--repair] [--input PATH|-]
+       aoc run --day 14 --steps N [--big] [--input PATH|-]
+       aoc test [--day N] [--part 1|2] [--stream]
+       aoc image --day 5|9|11|15 --output PATH.ppm|PATH.png [--part 1|2] [--steps N] [--scale N] [--input PATH|-]
+       aoc bench [--day N] [--runs N] [--output PATH.csv|PATH.json] [--baseline PATH.csv] [--threshold PERCENT]";
+
+/// Options of `aoc run` for a single day, and that day. None of them goes with `--stream`.
+const DAY_OPTIONS: &[(&str, u32)] = &[
+    ("--window", 1),
+    ("--trace", 2),
+    ("--analyse", 4),
+    ("--what-if", 4),
+    ("--at", 5),
+    ("--region", 5),
+    ("--analytic", 5),
+    ("--days", 6),
+    ("--exceeds", 6),
+    ("--display", 8),
+    ("--delimiters", 10),
+    ("--repair", 10),
+    ("--steps", 14),
+];
+
+/// Options of `aoc run` which don't go together.
+const CONFLICTS: &[(&str, &str)] = &[
+    ("--window", "--part"),
+    ("--analyse", "--what-if"),
+    ("--analyse", "--part"),
+    ("--what-if", "--part"),
+    ("--at", "--region"),
+    ("--analytic", "--region"),
+    ("--repair", "--part"),
+    ("--days", "--exceeds"),
+    ("--days", "--part"),
+    ("--exceeds", "--part"),
+    ("--steps", "--part"),
+];
+
+/// Options of `aoc run` and the options one of which they require.
+const REQUIREMENTS: &[(&str, &[&str])] = &[
+    ("--input", &["--day"]),
+    ("--trace", &["--part"]),
+    ("--diagonals", &["--analyse", "--what-if"]),
+    ("--lifecycle", &["--days", "--exceeds"]),
+    ("--big", &["--days", "--exceeds", "--steps"]),
+];
+
+struct RunArgs {
+    /// Every option given, for the checks.
+    options: Vec<String>,
+    day: Option<u32>,
+    part: Option<u32>,
+    input: Option<String>,
+    stream: bool,
+    /// Size of the windows of the day 01 report.
+    window: Option<usize>,
+    /// Day 02 positions as CSV.
+    trace: bool,
+    /// Day 04 boards ranked by the draw they win on.
+    analyse: bool,
+    /// Day 04 boards after these draws instead of the input's.
+    what_if: Option<Vec<u32>>,
+    /// Day 04 diagonals count for a bingo.
+    diagonals: bool,
+    /// Day 05 point whose overlapping lines are counted.
+    at: Option<day05::Point>,
+    /// Day 05 region whose dangerous points are counted.
+    region: Option<day05::Region>,
+    /// Day 05 overlaps from segment intersections instead of their points.
+    analytic: bool,
+    /// Day 08 table of the symbols shown instead of the digits.
+    display: Option<String>,
+    /// Day 10 delimiter pairs instead of the puzzle's.
+    delimiters: Option<String>,
+    /// Day 10 errors and repair of each line.
+    repair: bool,
+    /// Day 06 population after this many days.
+    days: Option<u64>,
+    /// Day 06 first day the population is larger than this.
+    exceeds: Option<String>,
+    /// Day 06 reset and newborn ages.
+    lifecycle: Option<day06::Lifecycle>,
+    /// Day 14 difference after this many steps.
+    steps: Option<usize>,
+    /// Day 06 and 14 count with big integers instead of 128 and 64 bits.
+    big: bool,
+}
+
+impl RunArgs {
+    fn parse(args: &[String]) -> Result<RunArgs, Box<dyn Error>> {
+        let mut run_args = RunArgs {
+            options: Vec::new(),
+            day: None,
+            part: None,
+            input: None,
+            stream: false,
+            window: None,
+            trace: false,
+            analyse: false,
+            what_if: None,
+            diagonals: false,
+            at: None,
+            region: None,
+            analytic: false,
+            display: None,
+            delimiters: None,
+            repair: false,
+            days: None,
+            exceeds: None,
+            lifecycle: None,
+            steps: None,
+            big: false,
+        };
+        let mut args = args.iter();
+        while let Some(flag) = args.next() {
+            run_args.options.push(flag.clone());
+            let value = match flag.as_str() {
+                "--stream" => {
+                    run_args.stream = true;
+                    continue;
+                }
+                "--trace" => {
+                    run_args.trace = true;
+                    continue;
+                }
+                "--analyse" => {
+                    run_args.analyse = true;
+                    continue;
+                }
+                "--diagonals" => {
+                    run_args.diagonals = true;
+                    continue;
+                }
+                "--analytic" => {
+                    run_args.analytic = true;
+                    continue;
+                }
+                "--repair" => {
+                    run_args.repair = true;
+                    continue;
+                }
+                "--big" => {
+                    run_args.big = true;
+                    continue;
+                }
+                _ => args.next().ok_or(format!("missing value for {}", flag))?,
+            };
+            match flag.as_str() {
+                "--day" => run_args.day = Some(value.parse()?),
+                "--part" => run_args.part = Some(value.parse()?),
+                "--input" => run_args.input = Some(value.clone()),
+                "--window" => run_args.window = Some(value.parse()?),
+                "--what-if" => run_args.what_if = Some(parse_list(flag, value)?),
+                "--display" => run_args.display = Some(value.clone()),
+                "--delimiters" => run_args.delimiters = Some(value.clone()),
+                "--days" => run_args.days = Some(value.parse()?),
+                "--exceeds" => run_args.exceeds = Some(value.clone()),
+                "--steps" => run_args.steps = Some(value.parse()?),
+                "--lifecycle" => match parse_list(flag, value)?[..] {
+                    [reset_age, newborn_age] => {
+                        run_args.lifecycle = Some(day06::Lifecycle {
+                            reset_age,
+                            newborn_age,
+                        })
+                    }
+                    _ => Err("--lifecycle takes RESET,NEWBORN")?,
+                },
+                "--at" => match parse_list(flag, value)?[..] {
+                    [x, y] => run_args.at = Some((x, y)),
+                    _ => Err("--at takes X,Y")?,
+                },
+                "--region" => match parse_list(flag, value)?[..] {
+                    [x1, y1, x2, y2] => {
+                        run_args.region = Some(day05::Region::new((x1, y1), (x2, y2)))
+                    }
+                    _ => Err("--region takes X1,Y1,X2,Y2")?,
+                },
+                _ => Err(format!("unknown option {}", flag))?,
+            }
+        }
+        if let Some(part) = run_args.part {
+            if part != 1 && part != 2 {
+                Err(format!("part must be 1 or 2, not {}", part))?;
+            }
+        }
+        if run_args.window == Some(0) {
+            Err("--window must be at least 1")?;
+        }
+        let given = |option: &str| run_args.options.iter().any(|o| o == option);
+        for &(option, day) in DAY_OPTIONS {
+            if given(option) && run_args.day != Some(day) {
+                Err(format!("{} requires --day {}", option, day))?;
+            }
+            if given(option) && run_args.stream {
+                Err(format!("{} doesn't go with --stream", option))?;
+            }
+        }
+        for &(option, other) in CONFLICTS {
+            if given(option) && given(other) {
+                Err(format!("{} and {} don't go together", option, other))?;
+            }
+        }
+        for &(option, needed) in REQUIREMENTS {
+            if given(option) && !needed.iter().any(|&o| given(o)) {
+                let (last, others) = needed.split_last().unwrap();
+                let needed = match others {
+                    [] => last.to_string(),
+                    _ => format!("{} or {}", others.join(", "), last),
+                };
+                Err(format!("{} requires {}", option, needed))?;
+            }
+        }
+        Ok(run_args)
+    }
+}
+
+/// Comma separated values of `flag`.
+fn parse_list<T: FromStr>(flag: &str, value: &str) -> Result<Vec<T>, Box<dyn Error>>
+where
+    T::Err: Display,
+{
+    value
+        .split(',')
+        .map(|n| n.parse())
+        .collect::<Result<_, T::Err>>()
+        .map_err(|e| format!("bad {} value {:?}: {}", flag, value, e).into())
+}
+
+struct ImageArgs {
+    day: u32,
+    part: Option<u32>,
+    input: Option<String>,
+    output: String,
+    /// Day 11 steps before the image.
+    steps: usize,
+    /// Pixels per cell in both directions.
+    scale: usize,
+}
+
+impl ImageArgs {
+    fn parse(args: &[String]) -> Result<ImageArgs, Box<dyn Error>> {
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        let mut output = None;
+        let mut steps = None;
+        let mut scale = 1;
+        let mut args = args.iter();
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or(format!("missing value for {}", flag))?;
+            match flag.as_str() {
+                "--day" => day = Some(value.parse()?),
+                "--part" => part = Some(value.parse()?),
+                "--input" => input = Some(value.clone()),
+                "--output" => output = Some(value.clone()),
+                "--steps" => steps = Some(value.parse()?),
+                "--scale" => scale = value.parse()?,
+                _ => Err(format!("unknown option {}", flag))?,
+            }
+        }
+        let day = day.ok_or("image requires --day")?;
+        if ![5, 9, 11, 15].contains(&day) {
+            Err(format!("no image for day {}", day))?;
+        }
+        if let Some(part) = part {
+            if ![5, 15].contains(&day) {
+                Err("--part only goes with --day 5 or 15")?;
+            }
+            if part != 1 && part != 2 {
+                Err(format!("part must be 1 or 2, not {}", part))?;
+            }
+        }
+        if steps.is_some() && day != 11 {
+            Err("--steps requires --day 11")?;
+        }
+        if scale == 0 {
+            Err("--scale must be at least 1")?;
+        }
+        let output = output.ok_or("image requires --output")?;
+        if !output.ends_with(".ppm") && !output.ends_with(".png") {
+            Err("--output must be a .ppm or .png file")?;
+        }
+        Ok(ImageArgs {
+            day,
+            part,
+            input,
+            output,
+            steps: steps.unwrap_or(0),
+            scale,
+        })
+    }
+}
+
+struct BenchArgs {
+    day: Option<u32>,
+    runs: usize,
+    output: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+impl BenchArgs {
+    fn parse(args: &[String]) -> Result<BenchArgs, Box<dyn Error>> {
+        let mut bench_args = BenchArgs {
+            day: None,
+            runs: 5,
+            output: None,
+            baseline: None,
+            threshold: 10.0,
+        };
+        let mut args = args.iter();
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or(format!("missing value for {}", flag))?;
+            match flag.as_str() {
+                "--day" => bench_args.day = Some(value.parse()?),
+                "--runs" => bench_args.runs = value.parse()?,
+                "--output" => bench_args.output = Some(value.clone()),
+                "--baseline" => bench_args.baseline = Some(value.clone()),
+                "--threshold" => bench_args.threshold = value.parse()?,
+                _ => Err(format!("unknown option {}", flag))?,
+            }
+        }
+        if bench_args.runs == 0 {
+            Err("--runs must be at least 1")?;
+        }
+        if let Some(baseline) = &bench_args.baseline {
+            if baseline.ends_with(".json") {
+                Err("--baseline must be a CSV file written by --output")?;
+            }
+        }
+        Ok(bench_args)
+    }
+}
+
+fn selected_days(day: Option<u32>) -> Result<Vec<Day>, Box<dyn Error>> {
+    match day {
+        Some(day) => Ok(vec![*DAYS
+            .iter()
+            .find(|d| d.number == day)
+            .ok_or(format!("no solution for day {}", day))?]),
+        None => Ok(DAYS.to_vec()),
+    }
+}
+
+fn default_input_filename(day: u32) -> String {
+    format!("{:02}/input.txt", day)
+}
+
+fn print_result(part: u32, result: &str) {
+    if result.contains('\n') {
+        println!("part{} result:\n{}", part, result);
+    } else {
+        println!("part{} result: {}", part, result);
+    }
+}
+
+/// Reader of the file `input_filename`, `-` being the standard input.
+fn open_input(input_filename: &str) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    if input_filename == "-" {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let file =
+        File::open(input_filename).map_err(|e| format!("cannot read {}: {}", input_filename, e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Answers `parts` of `day` for the input in `input_filename`, `-` being the standard input,
+/// with the solver chosen by the options of `args`.
+fn solve_input(
+    day: Day,
+    input_filename: &str,
+    parts: &[u32],
+    args: &RunArgs,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    if args.stream {
+        let solve_stream = day
+            .stream
+            .ok_or(format!("day {} can't stream its input", day.number))?;
+        return solve_stream(&mut *open_input(input_filename)?, parts);
+    }
+    let input = read_input(input_filename)?;
+    if args.analytic {
+        return solve_analytic(&input, parts);
+    }
+    if let Some(display) = &args.display {
+        return solve_display(display, &input, parts);
+    }
+    if let Some(delimiters) = &args.delimiters {
+        return solve_delimiters(delimiters, &input, parts);
+    }
+    if args.days.is_some() || args.exceeds.is_some() {
+        return solve_population(&input, parts, args);
+    }
+    if let Some(steps) = args.steps {
+        return solve_polymer(steps, &input, parts, args);
+    }
+    (day.solve)(&input, parts)
+}
+
+/// Day 05 parts from the intersections of the lines.
+fn solve_analytic(input: &str, parts: &[u32]) -> Result<Vec<String>, Box<dyn Error>> {
+    let segments = day05::Day05::parse(input)?;
+    parts
+        .iter()
+        .map(|&part| {
+            let intersections = day05::Intersections::new(&segments.0, directions(Some(part)));
+            Ok(intersections.dangerous().to_string())
+        })
+        .collect()
+}
+
+/// Day 08 parts with the symbols of the table in `display_filename`.
+fn solve_display(
+    display_filename: &str,
+    input: &str,
+    parts: &[u32],
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let display = day08::SegmentDisplay::load(display_filename)?;
+    let entries = day08::Day08::parse(input)?;
+    parts
+        .iter()
+        .map(|part| match part {
+            1 => Ok(day08::unique_outputs(&entries.0, &display).to_string()),
+            _ => Ok(day08::output_sum(&entries.0, &display)?.to_string()),
+        })
+        .collect()
+}
+
+/// Day 10 parts with the delimiter pairs in `delimiters_filename`.
+fn solve_delimiters(
+    delimiters_filename: &str,
+    input: &str,
+    parts: &[u32],
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let set = day10::DelimiterSet::load(delimiters_filename)?;
+    let lines = day10::Day10::parse(input)?;
+    parts
+        .iter()
+        .map(|part| match part {
+            1 => Ok(day10::corrupted_total(&lines.0, &set)?.to_string()),
+            _ => Ok(day10::middle_autocomplete_score(&lines.0, &set)?.to_string()),
+        })
+        .collect()
+}
+
+/// Day 06 population after `--days` or first day it exceeds `--exceeds`, for either part.
+fn solve_population(
+    input: &str,
+    parts: &[u32],
+    args: &RunArgs,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let pool = day06::Day06::parse(input)?;
+    let answer = if args.big {
+        population_answer::<BigUint>(&pool, args)?
+    } else {
+        population_answer::<u128>(&pool, args)?
+    };
+    Ok(parts.iter().map(|_| answer.clone()).collect())
+}
+
+fn population_answer<N: Unsigned>(
+    pool: &day06::LanternFishPool,
+    args: &RunArgs,
+) -> Result<String, Box<dyn Error>> {
+    let population = population::<N>(pool, args)?;
+    if let Some(threshold) = &args.exceeds {
+        let threshold = N::parse(threshold, threshold)?;
+        let day = population
+            .first_day_above(&threshold)?
+            .ok_or(format!("population never exceeds {}", threshold))?;
+        return Ok(day.to_string());
+    }
+    Ok(population.after(args.days.unwrap_or(0))?.to_string())
+}
+
+fn population<N: Unsigned>(
+    pool: &day06::LanternFishPool,
+    args: &RunArgs,
+) -> Result<day06::Population<N>, Box<dyn Error>> {
+    let lifecycle = args.lifecycle.unwrap_or(day06::Lifecycle::LANTERNFISH);
+    day06::Population::new(pool, lifecycle)
+}
+
+/// Day 14 difference after `steps` steps, for either part.
+fn solve_polymer(
+    steps: usize,
+    input: &str,
+    parts: &[u32],
+    args: &RunArgs,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let difference = element_spread(&day14::Day14::parse(input)?, steps, args)?;
+    Ok(parts.iter().map(|_| difference.clone()).collect())
+}
+
+fn element_spread(
+    polymerization: &day14::Polymerization,
+    steps: usize,
+    args: &RunArgs,
+) -> Result<String, Box<dyn Error>> {
+    if args.big {
+        Ok(polymerization.element_spread::<BigUint>(steps)?.to_string())
+    } else {
+        Ok(polymerization.element_spread::<u64>(steps)?.to_string())
+    }
+}
+
+fn read_input(input_filename: &str) -> Result<String, Box<dyn Error>> {
+    let mut file_content = String::new();
+    open_input(input_filename)?
+        .read_to_string(&mut file_content)
+        .map_err(|e| format!("cannot read {}: {}", input_filename, e))?;
+    Ok(file_content)
+}
+
+/// File given by `--input`, or the default input file of `day`.
+fn input_filename(args: &RunArgs, day: u32) -> String {
+    args.input
+        .clone()
+        .unwrap_or_else(|| default_input_filename(day))
+}
+
+/// Input of `S` in the file given by `--input`, or in its default input file.
+fn parse_input<S: Solution>(args: &RunArgs) -> Result<S::Input, Box<dyn Error>> {
+    Ok(S::parse(&read_input(&input_filename(args, S::DAY))?)?)
+}
+
+fn run_day(day: Day, args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let input_filename = input_filename(args, day.number);
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    for (part, result) in parts
+        .iter()
+        .zip(solve_input(day, &input_filename, &parts, args)?)
+    {
+        print_result(*part, &result);
+    }
+    Ok(())
+}
+
+/// Day 01 increases between windows of `window` depths.
+fn run_window_report(window: usize, args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let depths = parse_input::<day01::Day01>(args)?;
+    println!("{}", day01::window_report(&depths.0, window));
+    Ok(())
+}
+
+/// Day 02 positions after each command, with the movement model of the part.
+fn run_trace(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let commands = parse_input::<day02::Day02>(args)?;
+    let model = match args.part {
+        Some(1) => day02::Model::Plain,
+        _ => day02::Model::Aim,
+    };
+    let mut submarine = day02::Submarine::new(model);
+    submarine.trace(&commands.0, &mut io::stdout().lock())?;
+    Ok(())
+}
+
+/// Day 04 boards ranked over the input's draws, or where they stand after other draws.
+fn run_bingo_analysis(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let bingo = parse_input::<day04::Day04>(args)?;
+    match &args.what_if {
+        Some(draws) => println!("{}", bingo.what_if(draws, args.diagonals)),
+        None => println!("{}", bingo.analyse(args.diagonals)?),
+    }
+    Ok(())
+}
+
+/// Day 05 lines counted for `part`, only horizontal and vertical ones for part 1.
+fn directions(part: Option<u32>) -> &'static [day05::Direction] {
+    match part {
+        Some(1) => &[day05::Direction::Horizontal, day05::Direction::Vertical],
+        _ => &day05::Direction::ALL,
+    }
+}
+
+/// Day 05 lines overlapping at a point or the dangerous points of a region,
+/// only horizontal and vertical lines for part 1.
+fn run_overlaps(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let segments = parse_input::<day05::Day05>(args)?;
+    let overlaps = day05::Overlaps::new(&segments.0, directions(args.part));
+    if let Some((x, y)) = args.at {
+        println!("lines at {},{}: {}", x, y, overlaps.at((x, y)));
+    }
+    if let Some(region) = args.region {
+        println!(
+            "dangerous points from {},{} to {},{}: {}",
+            region.min.0,
+            region.min.1,
+            region.max.0,
+            region.max.1,
+            overlaps.count_in(&region, 2)
+        );
+    }
+    Ok(())
+}
+
+/// Day 05 lines at a point from the intersections of the lines.
+fn run_intersections(x: i32, y: i32, args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let segments = parse_input::<day05::Day05>(args)?;
+    let intersections = day05::Intersections::new(&segments.0, directions(args.part));
+    println!("lines at {},{}: {}", x, y, intersections.at((x, y)));
+    Ok(())
+}
+
+/// Day 06 population on a given day, or the first day it exceeds a size.
+fn run_population(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    if args.big {
+        run_population_in::<BigUint>(args)
+    } else {
+        run_population_in::<u128>(args)
+    }
+}
+
+fn run_population_in<N: Unsigned>(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let pool = parse_input::<day06::Day06>(args)?;
+    let population = population::<N>(&pool, args)?;
+    if let Some(days) = args.days {
+        println!(
+            "population after {} days: {}",
+            days,
+            population.after(days)?
+        );
+    }
+    if let Some(threshold) = &args.exceeds {
+        let threshold = N::parse(threshold, threshold)?;
+        match population.first_day_above(&threshold)? {
+            Some(day) => println!("population exceeds {} after {} days", threshold, day),
+            None => println!("population never exceeds {}", threshold),
+        }
+    }
+    Ok(())
+}
+
+/// Day 10 errors of each line and the line once repaired.
+fn run_repair(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let lines = parse_input::<day10::Day10>(args)?;
+    let set = match &args.delimiters {
+        Some(delimiters) => day10::DelimiterSet::load(delimiters)?,
+        None => day10::DelimiterSet::puzzle(),
+    };
+    print!("{}", day10::repair_report(&lines.0, &set));
+    Ok(())
+}
+
+/// Day 14 difference between the most and least common elements after a number of steps.
+fn run_polymer(steps: usize, args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let polymerization = parse_input::<day14::Day14>(args)?;
+    let difference = element_spread(&polymerization, steps, args)?;
+    println!("difference after {} steps: {}", steps, difference);
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = RunArgs::parse(args)?;
+    if let Some(window) = args.window {
+        return run_window_report(window, &args);
+    }
+    if args.trace {
+        return run_trace(&args);
+    }
+    if args.analyse || args.what_if.is_some() {
+        return run_bingo_analysis(&args);
+    }
+    if let (true, Some((x, y))) = (args.analytic, args.at) {
+        return run_intersections(x, y, &args);
+    }
+    if args.at.is_some() || args.region.is_some() {
+        return run_overlaps(&args);
+    }
+    if args.repair {
+        return run_repair(&args);
+    }
+    if args.days.is_some() || args.exceeds.is_some() {
+        return run_population(&args);
+    }
+    if let Some(steps) = args.steps {
+        return run_polymer(steps, &args);
+    }
+    let days = selected_days(args.day)?;
+    if let [day] = days[..] {
+        return run_day(day, &args);
+    }
+    for day in days {
+        println!(
+            "==================== Day {:02} ====================",
+            day.number
+        );
+        if let Err(e) = run_day(day, &args) {
+            eprintln!("error: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Checks every recorded answer of `day`, returns the number of (passed, failed) checks.
+fn test_day(day: Day, args: &RunArgs) -> Result<(usize, usize), Box<dyn Error>> {
+    let answers: Vec<answers::Answer> = answers::load(day.number)?
+        .into_iter()
+        .filter(|a| args.part.is_none() || args.part == Some(a.part))
+        .collect();
+    // the answers solved together, for the same input and options
+    let mut runs: Vec<(&str, &[String])> = Vec::new();
+    for a in &answers {
+        let run = (a.input.as_str(), &a.options[..]);
+        if !runs.contains(&run) {
+            runs.push(run);
+        }
+    }
+
+    let (mut passed, mut failed) = (0, 0);
+    for (input, options) in runs {
+        // the streaming solvers only answer the plain runs
+        if args.stream && !options.is_empty() {
+            continue;
+        }
+        let input_filename = format!("{:02}/{}", day.number, input);
+        let name = [input_filename.as_str()]
+            .into_iter()
+            .chain(options.iter().map(|o| o.as_str()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let run_answers: Vec<&answers::Answer> = answers
+            .iter()
+            .filter(|a| a.input == input && a.options == options)
+            .collect();
+        let parts: Vec<u32> = run_answers.iter().map(|a| a.part).collect();
+        let mut run_args = vec!["--day".to_string(), day.number.to_string()];
+        run_args.extend(run_answers[0].run_args());
+        if args.stream {
+            run_args.push("--stream".to_string());
+        }
+        let results = RunArgs::parse(&run_args)
+            .and_then(|run_args| solve_input(day, &input_filename, &parts, &run_args));
+        let results = match results {
+            Ok(results) => results,
+            Err(e) => {
+                println!("{} FAILED: {}", name, e);
+                failed += run_answers.len();
+                continue;
+            }
+        };
+        for (answer, result) in run_answers.iter().zip(results) {
+            if answer.matches(&result) {
+                println!("{} part{} ok", name, answer.part);
+                passed += 1;
+            } else {
+                println!(
+                    "{} part{} FAILED: expected {:?}, got {:?}",
+                    name, answer.part, answer.expected, result
+                );
+                failed += 1;
+            }
+        }
+    }
+    Ok((passed, failed))
+}
+
+fn test(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = RunArgs::parse(args)?;
+    let unsupported = args
+        .options
+        .iter()
+        .find(|o| !["--day", "--part", "--stream"].contains(&o.as_str()));
+    if let Some(option) = unsupported {
+        Err(format!(
+            "{} is not supported by test, inputs and options come from the answers files",
+            option
+        ))?;
+    }
+    let (mut passed, mut failed) = (0, 0);
+    for day in selected_days(args.day)? {
+        if args.stream && day.stream.is_none() {
+            continue;
+        }
+        let (p, f) = test_day(day, &args)?;
+        passed += p;
+        failed += f;
+    }
+    println!("{} passed, {} failed", passed, failed);
+    if failed != 0 {
+        Err(format!("{} answer(s) did not match", failed))?;
+    }
+    Ok(())
+}
+
+/// Image of the state of a solver, written as PPM or PNG depending on the output name.
+fn image(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = ImageArgs::parse(args)?;
+    let input_filename = args
+        .input
+        .clone()
+        .unwrap_or_else(|| default_input_filename(args.day));
+    let input = read_input(&input_filename)?;
+    let image = match args.day {
+        5 => {
+            let segments = day05::Day05::parse(&input)?;
+            day05::Overlaps::new(&segments.0, directions(args.part)).image()?
+        }
+        9 => day09::Day09::parse(&input)?.image(),
+        11 => {
+            let mut octopuses = day11::Day11::parse(&input)?;
+            for _ in 0..args.steps {
+                octopuses.step();
+            }
+            octopuses.image()
+        }
+        15 => {
+            let times = match args.part {
+                Some(1) => 1,
+                _ => 5,
+            };
+            day15::Day15::parse(&input)?.image(times)?
+        }
+        day => Err(format!("no image for day {}", day))?,
+    };
+    image::check_size(image.width() * args.scale, image.height() * args.scale)?;
+    image.scaled(args.scale).save(&args.output)?;
+    println!(
+        "wrote {}x{} image to {}",
+        image.width() * args.scale,
+        image.height() * args.scale,
+        args.output
+    );
+    Ok(())
+}
+
+fn bench(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = BenchArgs::parse(args)?;
+    let mut measures = Vec::new();
+    for day in selected_days(args.day)? {
+        let input_filename = default_input_filename(day.number);
+        let day_measures = fs::read_to_string(&input_filename)
+            .map_err(|e| format!("cannot read {}: {}", input_filename, e).into())
+            .and_then(|file_content| (day.bench)(&file_content, args.runs));
+        match day_measures {
+            Ok(day_measures) => {
+                for m in &day_measures {
+                    println!("{}", m);
+                }
+                measures.extend(day_measures);
+            }
+            Err(e) => eprintln!("day {:02} error: {}", day.number, e),
+        }
+    }
+
+    if let Some(output) = &args.output {
+        bench::save(output, &measures)?;
+    }
+    if let Some(baseline) = &args.baseline {
+        let baseline = bench::load_csv(baseline)?;
+        let regressions = bench::regressions(&measures, &baseline, args.threshold);
+        for r in &regressions {
+            println!(
+                "day {:02} {} REGRESSED: median {:.2?} -> {:.2?} (+{:.1}%)",
+                r.measure.day,
+                r.measure.step.name(),
+                r.baseline.median,
+                r.measure.median,
+                r.percent
+            );
+        }
+        if !regressions.is_empty() {
+            Err(format!(
+                "{} step(s) slower than the baseline by more than {}%",
+                regressions.len(),
+                args.threshold
+            ))?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("test") => test(&args[1..]),
+        Some("image") => image(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        _ => Err(USAGE.into()),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,268 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError, ParseErrorKind};
+
+/// Unsigned integers counters can be generic over, fixed width ones or `BigUint`.
+pub trait Unsigned:
+    Clone
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    /// Largest value, `None` if there's none.
+    fn max_value() -> Option<Self>;
+
+    /// Decimal number `s`, a slice of `input`.
+    fn parse(input: &str, s: &str) -> Result<Self, ParseError>;
+
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    fn saturating_add(&self, other: &Self) -> Self {
+        self.checked_add(other)
+            .or_else(Self::max_value)
+            .expect("only bounded integers overflow")
+    }
+
+    fn saturating_mul(&self, other: &Self) -> Self {
+        self.checked_mul(other)
+            .or_else(Self::max_value)
+            .expect("only bounded integers overflow")
+    }
+
+    /// Whether a saturating operation may have stopped at this value.
+    fn is_max(&self) -> bool {
+        Self::max_value().is_some_and(|max| *self == max)
+    }
+}
+
+macro_rules! fixed_width_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Unsigned for $t {
+                fn max_value() -> Option<Self> {
+                    Some(<$t>::MAX)
+                }
+
+                fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+                    parse::number(input, s)
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+fixed_width_unsigned!(u64, u128);
+
+/// Unsigned integer of any size, stored as base 2^32 digits from the least significant one
+/// without leading zeros.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint(Vec<u32>);
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint(Vec::new())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn normalized(mut digits: Vec<u32>) -> BigUint {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        BigUint(digits)
+    }
+
+    /// `self * factor + term` for small numbers, which is all parsing needs.
+    fn mul_add_small(&self, factor: u32, term: u32) -> BigUint {
+        let mut carry = term as u64;
+        let mut digits = Vec::with_capacity(self.0.len() + 1);
+        for &d in &self.0 {
+            let v = d as u64 * factor as u64 + carry;
+            digits.push(v as u32);
+            carry = v >> 32;
+        }
+        digits.push(carry as u32);
+        BigUint::normalized(digits)
+    }
+
+    /// Quotient and remainder of the division by a small number.
+    fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        let mut remainder = 0u64;
+        let mut digits = vec![0; self.0.len()];
+        for (i, &d) in self.0.iter().enumerate().rev() {
+            let v = (remainder << 32) | d as u64;
+            digits[i] = (v / divisor as u64) as u32;
+            remainder = v % divisor as u64;
+        }
+        (BigUint::normalized(digits), remainder as u32)
+    }
+}
+
+impl From<u8> for BigUint {
+    fn from(n: u8) -> BigUint {
+        BigUint::from(n as u128)
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> BigUint {
+        BigUint::from(n as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut n: u128) -> BigUint {
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push(n as u32);
+            n >>= 32;
+        }
+        BigUint(digits)
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: BigUint) -> BigUint {
+        let (long, short) = if self.0.len() >= other.0.len() {
+            (self.0, other.0)
+        } else {
+            (other.0, self.0)
+        };
+        let mut digits = Vec::with_capacity(long.len() + 1);
+        let mut carry = 0u64;
+        for (i, &d) in long.iter().enumerate() {
+            let v = d as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+            digits.push(v as u32);
+            carry = v >> 32;
+        }
+        digits.push(carry as u32);
+        BigUint::normalized(digits)
+    }
+}
+
+impl Sub for BigUint {
+    type Output = BigUint;
+
+    /// Panics if `other` is larger, like the fixed width integers with overflow checks.
+    fn sub(self, other: BigUint) -> BigUint {
+        assert!(self >= other, "attempt to subtract with overflow");
+        let mut digits = Vec::with_capacity(self.0.len());
+        let mut borrow = 0i64;
+        for (i, &d) in self.0.iter().enumerate() {
+            let mut v = d as i64 - other.0.get(i).copied().unwrap_or(0) as i64 - borrow;
+            borrow = (v < 0) as i64;
+            if v < 0 {
+                v += 1 << 32;
+            }
+            digits.push(v as u32);
+        }
+        BigUint::normalized(digits)
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        let mut digits = vec![0u32; self.0.len() + other.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.0.iter().enumerate() {
+                let v = a as u64 * b as u64 + digits[i + j] as u64 + carry;
+                digits[i + j] = v as u32;
+                carry = v >> 32;
+            }
+            digits[i + other.0.len()] = carry as u32;
+        }
+        BigUint::normalized(digits)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // groups of 9 decimal digits from the least significant one
+        let mut groups = Vec::new();
+        let mut n = self.clone();
+        while !n.is_zero() {
+            let (quotient, group) = n.div_rem_small(1_000_000_000);
+            groups.push(group);
+            n = quotient;
+        }
+        let mut s = groups.pop().unwrap_or(0).to_string();
+        for group in groups.iter().rev() {
+            s.push_str(&format!("{:09}", group));
+        }
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::at(s, s, ParseErrorKind::Missing("number")));
+        }
+        s.char_indices()
+            .try_fold(BigUint::zero(), |n, (i, c)| match c.to_digit(10) {
+                Some(digit) => Ok(n.mul_add_small(10, digit)),
+                None => Err(ParseError::at(s, &s[i..], ParseErrorKind::InvalidChar(c))),
+            })
+    }
+}
+
+impl Unsigned for BigUint {
+    fn max_value() -> Option<Self> {
+        None
+    }
+
+    fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        s.parse().map_err(|e: ParseError| e.within(input, s))
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self.clone() + other.clone())
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self.clone() * other.clone())
+    }
+}
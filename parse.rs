@@ -0,0 +1,211 @@
+use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A separator or prefix that should be there isn't.
+    Missing(&'static str),
+    InvalidNumber(String),
+    InvalidChar(char),
+    Empty,
+    Invalid(String),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ParseErrorKind::*;
+        match self {
+            Missing(s) => write!(f, "expected {:?}", s),
+            InvalidNumber(reason) => write!(f, "invalid number: {}", reason),
+            InvalidChar(c) => write!(f, "unexpected character {:?}", c),
+            Empty => write!(f, "empty input"),
+            Invalid(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// Where and why an input couldn't be parsed.
+///
+/// `line` and `column` start at 1 and are relative to the text given to the
+/// parser that failed, `within` moves them to an enclosing text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub day: Option<u32>,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+}
+
+/// Byte offset of `fragment` in `input`, 0 if it isn't a slice of it.
+fn offset_in(input: &str, fragment: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let fragment_start = fragment.as_ptr() as usize;
+    if fragment_start >= start && fragment_start + fragment.len() <= start + input.len() {
+        fragment_start - start
+    } else {
+        0
+    }
+}
+
+/// 1-based line and column (in characters) of `offset` in `input` and the text of that line.
+fn locate(input: &str, offset: usize) -> (usize, usize, String) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = input[line_start..offset].chars().count() + 1;
+    let snippet = input[line_start..].lines().next().unwrap_or("").to_string();
+    (line, column, snippet)
+}
+
+impl ParseError {
+    /// Error about `fragment`, which should be a slice of `input`.
+    pub fn at(input: &str, fragment: &str, kind: ParseErrorKind) -> ParseError {
+        let (line, column, snippet) = locate(input, offset_in(input, fragment));
+        ParseError {
+            kind,
+            day: None,
+            line,
+            column,
+            snippet,
+        }
+    }
+
+    /// Moves an error about `inner` to its position in `outer`, `inner` being a slice of `outer`.
+    pub fn within(self, outer: &str, inner: &str) -> ParseError {
+        let (inner_line, inner_column, _) = locate(outer, offset_in(outer, inner));
+        let line = inner_line + self.line - 1;
+        let column = if self.line == 1 {
+            inner_column + self.column - 1
+        } else {
+            self.column
+        };
+        let snippet = outer.lines().nth(line - 1).unwrap_or("").to_string();
+        ParseError {
+            line,
+            column,
+            snippet,
+            ..self
+        }
+    }
+
+    /// Moves an error about a line read on its own to line `line` of the whole input.
+    pub fn on_line(self, line: usize) -> ParseError {
+        ParseError {
+            line: line + self.line - 1,
+            ..self
+        }
+    }
+
+    pub fn with_day(self, day: u32) -> ParseError {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )?;
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Integer parsed by a plain `str::parse`, the error is at the start of the parsed text.
+impl From<ParseIntError> for ParseError {
+    fn from(e: ParseIntError) -> ParseError {
+        ParseError {
+            kind: ParseErrorKind::InvalidNumber(e.to_string()),
+            day: None,
+            line: 1,
+            column: 1,
+            snippet: String::new(),
+        }
+    }
+}
+
+/// `str::split_once` reporting the end of `s` when `separator` isn't found.
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    separator: &'static str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::at(input, &s[s.len()..], ParseErrorKind::Missing(separator)))
+}
+
+/// `str::strip_prefix` reporting the start of `s` when it doesn't start with `prefix`.
+pub fn strip_prefix<'a>(
+    input: &str,
+    s: &'a str,
+    prefix: &'static str,
+) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, s, ParseErrorKind::Missing(prefix)))
+}
+
+/// Parses the number `s`, a slice of `input`.
+pub fn number<T>(input: &str, s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    s.parse().map_err(|e: T::Err| {
+        ParseError::at(
+            input,
+            s,
+            ParseErrorKind::InvalidNumber(format!("{:?} {}", s, e)),
+        )
+    })
+}
+
+/// Parses each of the `separator` separated fields of `s`, a slice of `input`.
+pub fn separated<T>(input: &str, s: &str, separator: &'static str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    s.split(separator).map(|n| number(input, n)).collect()
+}
+
+/// Parses every line of `s` as a `T`, errors being located in `s`.
+pub fn lines<T>(s: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    s.lines()
+        .map(|l| l.parse().map_err(|e: T::Err| e.into().within(s, l)))
+        .collect()
+}
+
+/// Parses the lines read from `reader` one at a time as `T`s, errors being located on their line.
+pub fn read_lines<'a, T>(
+    reader: &'a mut dyn BufRead,
+) -> impl Iterator<Item = Result<T, Box<dyn Error>>> + 'a
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    reader.lines().enumerate().map(|(i, line)| {
+        let line = line?;
+        line.parse()
+            .map_err(|e: T::Err| e.into().within(&line, &line).on_line(i + 1).into())
+    })
+}
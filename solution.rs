@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+
+/// A day of the calendar: how to parse the puzzle input and how to answer both parts.
+pub trait Solution {
+    const DAY: u32;
+
+    type Input: FromStr<Err = ParseError>;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        s.parse().map_err(|e: ParseError| e.with_day(Self::DAY))
+    }
+
+    /// Parses `s` once and returns the displayed answer of each requested part.
+    fn solve(s: &str, parts: &[u32]) -> Result<Vec<String>, Box<dyn Error>> {
+        let input = Self::parse(s)?;
+        parts
+            .iter()
+            .map(|part| match part {
+                1 => Ok(Self::part1(&input)?.to_string()),
+                2 => Ok(Self::part2(&input)?.to_string()),
+                _ => Err(format!("no part {}", part).into()),
+            })
+            .collect()
+    }
+}
+
+/// Day whose answers can be computed in a single pass over its input, one line at a time,
+/// without holding the whole input in memory.
+pub trait Streaming: Solution {
+    fn solve_stream(reader: &mut dyn BufRead)
+        -> Result<(Self::Part1, Self::Part2), Box<dyn Error>>;
+
+    /// Same as `Solution::solve` for an input read from `reader`.
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        parts: &[u32],
+    ) -> Result<Vec<String>, Box<dyn Error>> {
+        let (part1, part2) =
+            Self::solve_stream(reader).map_err(|e| match e.downcast::<ParseError>() {
+                Ok(e) => Box::new(e.with_day(Self::DAY)),
+                Err(e) => e,
+            })?;
+        parts
+            .iter()
+            .map(|part| match part {
+                1 => Ok(part1.to_string()),
+                2 => Ok(part2.to_string()),
+                _ => Err(format!("no part {}", part).into()),
+            })
+            .collect()
+    }
+}
+
+/// Input made of one `T` per line.
+pub struct Lines<T>(pub Vec<T>);
+
+impl<T> FromStr for Lines<T>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Lines(parse::lines(s)?))
+    }
+}